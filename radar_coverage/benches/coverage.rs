@@ -1,9 +1,9 @@
 use criterion::{black_box, criterion_group, criterion_main, Criterion};
-use radar_coverage::coverage::compute_coverage_tile;
+use radar_coverage::coverage::{compute_coverage_tile, CoverageRequest};
 use radar_coverage::terrain::{TerrainManager, TerrainLoader};
-use radar_coverage::physics::los::{LosSystem};
-use radar_coverage::physics::refraction::RefractionParams;
+use radar_coverage::physics::viewshed::{HorizonGrid, Viewshed};
 use radar_coverage::io::Radar;
+use radar_coverage::jobs::JobContext;
 use radar_coverage::geo::LatLon;
 use std::path::PathBuf;
 use std::sync::Arc;
@@ -27,21 +27,27 @@ fn coverage_benchmark(c: &mut Criterion) {
         snr_threshold_db: 10.0,
         azimuth_sector: None,
         elevation_sector: None,
+        ..Default::default()
     };
     
-    let los = LosSystem::new(RefractionParams { k_factor: 1.33 });
+    // Unmasked viewshed: the benchmark measures the per-cell coverage work, not the horizon sweep
+    let viewshed: Arc<HorizonGrid> = Arc::new(Viewshed::new(radar.location, 200_000.0, 100.0).into());
+    let request = CoverageRequest {
+        target_altitude: 50.0,
+        step_size: 1, // Full resolution
+        ..Default::default()
+    };
 
     c.bench_function("compute_coverage_tile", |b| {
         b.iter(|| {
             compute_coverage_tile(
                 black_box(radar.clone()),
                 black_box(terrain_manager.clone()),
-                black_box(los),
+                black_box(viewshed.clone()),
                 black_box(45),
                 black_box(5),
-                black_box(1.0),
-                black_box(50.0),
-                black_box(1), // Full resolution
+                black_box(&request),
+                &JobContext::default(),
             )
         })
     });
@@ -22,6 +22,7 @@ fn main() {
         snr_threshold_db: 10.0,
         azimuth_sector: None,
         elevation_sector: None,
+        ..Default::default()
     };
 
     println!("Radar params: Alt={}", radar.location.altitude);
@@ -2,13 +2,16 @@ use bevy::prelude::*;
 use lru::LruCache;
 use std::sync::{Arc, Mutex};
 use std::num::NonZeroUsize;
-use crate::coverage::CoverageTile;
+use crate::coverage::{AltitudeReference, CoverageTile};
+
+pub mod viewshed;
 
 #[derive(Hash, PartialEq, Eq, Clone, Copy, Debug)]
 pub struct CoverageKey {
     pub lat: i32,
     pub lon: i32,
-    pub target_agl_m: i16, 
+    pub target_altitude_m: i16,
+    pub reference: AltitudeReference,
     pub radar_hash: u64,
     // We might want to include refraction params hash here too
     // For MVP, assuming constant refraction or clearing cache on change is simpler
@@ -50,3 +53,25 @@ impl CoverageCache {
         cache.clear();
     }
 }
+
+/// FNV-1a: unlike `DefaultHasher`, stable across builds, so usable for on-disk keys
+#[derive(Debug, Clone, Copy)]
+pub struct StableHasher(u64);
+
+impl Default for StableHasher {
+    fn default() -> Self {
+        Self(0xcbf2_9ce4_8422_2325)
+    }
+}
+
+impl std::hash::Hasher for StableHasher {
+    fn finish(&self) -> u64 {
+        self.0
+    }
+
+    fn write(&mut self, bytes: &[u8]) {
+        for byte in bytes {
+            self.0 = (self.0 ^ *byte as u64).wrapping_mul(0x0100_0000_01b3);
+        }
+    }
+}
@@ -0,0 +1,265 @@
+use std::fs::File;
+use std::hash::{Hash, Hasher};
+use std::io::{BufReader, BufWriter, Read, Write};
+use std::path::{Path, PathBuf};
+use std::time::SystemTime;
+use anyhow::{bail, Result};
+use bevy::prelude::Resource;
+use flate2::Compression;
+use flate2::read::GzDecoder;
+use flate2::write::GzEncoder;
+use crate::cache::StableHasher;
+use crate::geo::LatLon;
+use crate::io::Radar;
+use crate::physics::polar_viewshed::PolarViewshed;
+use crate::physics::viewshed::{HorizonGrid, Viewshed, ViewshedGrid, ViewshedSettings};
+
+const MAGIC: &[u8; 4] = b"RCVS";
+/// Largest grid accepted from a file (~1 GB of angles); a full-range 100 m
+/// Cartesian grid is under 10⁸ cells
+const MAX_GRID_CELLS: usize = 1 << 28;
+/// Bumped whenever the file layout or the viewshed algorithms change meaning
+pub const VIEWSHED_FORMAT_VERSION: u32 = 1;
+
+/// Default size cap of the store directory; least recently used files go first
+pub const VIEWSHED_STORE_MAX_BYTES: u64 = 2_000_000_000;
+
+/// Resolution at which the k-factor selects a viewshed
+pub const K_FACTOR_STEP: f32 = 0.01;
+
+/// K-factor rounded to `K_FACTOR_STEP`, so that dragging the slider only
+/// recomputes viewsheds when the value moves by a visible amount
+pub fn quantize_k_factor(k_factor: f32) -> f32 {
+    (k_factor / K_FACTOR_STEP).round() * K_FACTOR_STEP
+}
+
+/// Everything a stored viewshed depends on
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct ViewshedKey {
+    pub location: LatLon,
+    pub antenna_altitude_m: f64, // AMSL, covers mast height and platform
+    pub k_factor: f32,
+    pub terrain_fingerprint: u64,
+    pub settings: ViewshedSettings,
+}
+
+impl ViewshedKey {
+    pub fn new(radar: &Radar, k_factor: f32, settings: &ViewshedSettings, terrain_fingerprint: u64) -> Self {
+        Self {
+            location: radar.location,
+            antenna_altitude_m: radar.antenna_altitude_amsl(),
+            k_factor: quantize_k_factor(k_factor),
+            terrain_fingerprint,
+            settings: *settings,
+        }
+    }
+
+    pub fn digest(&self) -> u64 {
+        let mut hasher = StableHasher::default();
+        VIEWSHED_FORMAT_VERSION.hash(&mut hasher);
+        self.location.latitude.to_bits().hash(&mut hasher);
+        self.location.longitude.to_bits().hash(&mut hasher);
+        self.antenna_altitude_m.to_bits().hash(&mut hasher);
+        self.k_factor.to_bits().hash(&mut hasher);
+        self.terrain_fingerprint.hash(&mut hasher);
+        self.settings.range_m.to_bits().hash(&mut hasher);
+        match self.settings.grid {
+            ViewshedGrid::Cartesian => {
+                0u8.hash(&mut hasher);
+                self.settings.cell_size_m.to_bits().hash(&mut hasher);
+                self.settings.algorithm.hash(&mut hasher);
+            }
+            // The algorithm and cell size only apply to the Cartesian grid
+            ViewshedGrid::Polar { range_bin_m, azimuth_bin_deg } => {
+                1u8.hash(&mut hasher);
+                range_bin_m.to_bits().hash(&mut hasher);
+                azimuth_bin_deg.to_bits().hash(&mut hasher);
+            }
+        }
+        hasher.finish()
+    }
+}
+
+/// Directory of computed viewsheds, one gzip-compressed binary file per key.
+///
+/// Layout: "RCVS", format version (u32), key digest (u64), then the compressed
+/// grid. Horizon angles are stored byte-planar (all first bytes, then all second
+/// bytes...) which deflate compresses far better than interleaved floats.
+///
+/// Loads refresh a file's modification time, and each save evicts the least
+/// recently used files until the directory fits in `max_bytes`.
+#[derive(Debug, Clone, Resource)]
+pub struct ViewshedStore {
+    pub dir: PathBuf,
+    pub max_bytes: u64,
+}
+
+impl ViewshedStore {
+    pub fn new<P: Into<PathBuf>>(dir: P) -> Self {
+        Self { dir: dir.into(), max_bytes: VIEWSHED_STORE_MAX_BYTES }
+    }
+
+    pub fn path(&self, key: &ViewshedKey) -> PathBuf {
+        self.dir.join(format!("{:016x}.rcvs", key.digest()))
+    }
+
+    /// Stored viewshed for `key`, None when missing, stale or unreadable
+    pub fn load(&self, key: &ViewshedKey) -> Option<HorizonGrid> {
+        let file = File::open(self.path(key)).ok()?;
+        // Marks the file as recently used; failing only makes it an earlier eviction
+        let _ = file.set_modified(SystemTime::now());
+        match read_grid(BufReader::new(file), key.digest()) {
+            Ok(grid) => grid,
+            Err(e) => {
+                eprintln!("Ignoring unreadable viewshed {:?}: {}", self.path(key), e);
+                None
+            }
+        }
+    }
+
+    pub fn save(&self, key: &ViewshedKey, grid: &HorizonGrid) -> Result<()> {
+        std::fs::create_dir_all(&self.dir)?;
+        // Write then rename, so that an interrupted save never leaves a truncated file
+        let path = self.path(key);
+        let tmp = path.with_extension("tmp");
+        write_grid(BufWriter::new(File::create(&tmp)?), key.digest(), grid)?;
+        std::fs::rename(tmp, &path)?;
+        self.evict(&path)
+    }
+
+    // Removes the oldest stored viewsheds, never `keep`, until the store fits `max_bytes`
+    fn evict(&self, keep: &Path) -> Result<()> {
+        let mut files = Vec::new();
+        for entry in std::fs::read_dir(&self.dir)? {
+            let path = entry?.path();
+            if path.extension().is_none_or(|e| e != "rcvs") {
+                continue;
+            }
+            let metadata = std::fs::metadata(&path)?;
+            files.push((metadata.modified()?, metadata.len(), path));
+        }
+        let mut total: u64 = files.iter().map(|(_, len, _)| len).sum();
+        files.sort();
+        for (_, len, path) in files {
+            if total <= self.max_bytes {
+                break;
+            }
+            if path == keep {
+                continue;
+            }
+            std::fs::remove_file(&path)?;
+            total -= len;
+        }
+        Ok(())
+    }
+}
+
+pub fn write_grid<W: Write>(mut writer: W, digest: u64, grid: &HorizonGrid) -> Result<()> {
+    writer.write_all(MAGIC)?;
+    writer.write_all(&VIEWSHED_FORMAT_VERSION.to_le_bytes())?;
+    writer.write_all(&digest.to_le_bytes())?;
+
+    let mut gz = GzEncoder::new(writer, Compression::default());
+    let (origin, values) = match grid {
+        HorizonGrid::Cartesian(v) => (v.origin, &v.horizon_map),
+        HorizonGrid::Polar(v) => (v.origin, &v.horizon_map),
+    };
+    let kind: u8 = match grid {
+        HorizonGrid::Cartesian(_) => 0,
+        HorizonGrid::Polar(_) => 1,
+    };
+    gz.write_all(&[kind])?;
+    for value in [origin.latitude, origin.longitude, origin.altitude] {
+        gz.write_all(&value.to_le_bytes())?;
+    }
+    match grid {
+        HorizonGrid::Cartesian(v) => {
+            gz.write_all(&v.radius_m.to_le_bytes())?;
+            gz.write_all(&v.cell_size_m.to_le_bytes())?;
+            gz.write_all(&(v.width as u32).to_le_bytes())?;
+            gz.write_all(&(v.height as u32).to_le_bytes())?;
+        }
+        HorizonGrid::Polar(v) => {
+            gz.write_all(&v.range_bin_m.to_le_bytes())?;
+            gz.write_all(&v.azimuth_bin_deg.to_le_bytes())?;
+            gz.write_all(&(v.n_ranges as u32).to_le_bytes())?;
+            gz.write_all(&(v.n_azimuths as u32).to_le_bytes())?;
+        }
+    }
+    for byte in 0..4 {
+        let plane: Vec<u8> = values.iter().map(|v| v.to_le_bytes()[byte]).collect();
+        gz.write_all(&plane)?;
+    }
+    gz.finish()?.flush()?;
+    Ok(())
+}
+
+/// Ok(None) when the file belongs to another key or format version
+pub fn read_grid<R: Read>(mut reader: R, digest: u64) -> Result<Option<HorizonGrid>> {
+    let mut header = [0u8; 16];
+    reader.read_exact(&mut header)?;
+    if &header[0..4] != MAGIC {
+        bail!("not a viewshed file");
+    }
+    let version = u32::from_le_bytes(header[4..8].try_into()?);
+    let stored_digest = u64::from_le_bytes(header[8..16].try_into()?);
+    if version != VIEWSHED_FORMAT_VERSION || stored_digest != digest {
+        return Ok(None);
+    }
+
+    let mut gz = GzDecoder::new(reader);
+    let mut kind = [0u8; 1];
+    gz.read_exact(&mut kind)?;
+    let origin = LatLon { latitude: read_f64(&mut gz)?, longitude: read_f64(&mut gz)?, altitude: read_f64(&mut gz)? };
+    let (a, b) = (read_f64(&mut gz)?, read_f64(&mut gz)?);
+    let (n, m) = (read_u32(&mut gz)? as usize, read_u32(&mut gz)? as usize);
+
+    // Dimensions must follow from the stored geometry, as `Viewshed::new` and
+    // `PolarViewshed::new` derive them, before anything is allocated
+    let expected = match kind[0] {
+        0 => {
+            let size = (a * 2.0 / b).ceil();
+            (size.is_finite() && size >= 0.0).then_some((size as usize, size as usize))
+        }
+        1 => {
+            let n_azimuths = (360.0 / b).round();
+            (a >= 1.0 && n_azimuths.is_finite() && n_azimuths >= 1.0).then_some((n, n_azimuths as usize))
+        }
+        other => bail!("unknown grid kind {}", other),
+    };
+    if expected != Some((n, m)) {
+        bail!("grid of {} x {} does not match its geometry", n, m);
+    }
+    let len = match n.checked_mul(m) {
+        Some(len) if len <= MAX_GRID_CELLS => len,
+        _ => bail!("grid of {} x {} is too large", n, m),
+    };
+
+    let mut planes = vec![0u8; len * 4];
+    gz.read_exact(&mut planes)?;
+    // Reaching the end checks the gzip trailer (CRC and length)
+    if gz.read(&mut [0u8; 1])? != 0 {
+        bail!("trailing data after the grid");
+    }
+    let horizon_map: Vec<f32> = (0..len)
+        .map(|i| f32::from_le_bytes([planes[i], planes[len + i], planes[2 * len + i], planes[3 * len + i]]))
+        .collect();
+
+    let grid = match kind[0] {
+        0 => HorizonGrid::Cartesian(Viewshed { origin, radius_m: a, cell_size_m: b, width: n, height: m, horizon_map }),
+        _ => HorizonGrid::Polar(PolarViewshed { origin, range_bin_m: a, azimuth_bin_deg: b, n_ranges: n, n_azimuths: m, horizon_map }),
+    };
+    Ok(Some(grid))
+}
+
+fn read_f64<R: Read>(reader: &mut R) -> Result<f64> {
+    let mut bytes = [0u8; 8];
+    reader.read_exact(&mut bytes)?;
+    Ok(f64::from_le_bytes(bytes))
+}
+
+fn read_u32<R: Read>(reader: &mut R) -> Result<u32> {
+    let mut bytes = [0u8; 4];
+    reader.read_exact(&mut bytes)?;
+    Ok(u32::from_le_bytes(bytes))
+}
@@ -0,0 +1,118 @@
+use crate::coverage::{CoverageClass, CoverageTile};
+
+/// `CompositeTile::best_radar` of a cell no radar reaches
+pub const NO_RADAR: u8 = u8::MAX;
+
+/// How the fused network layer decides that a cell is covered
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub enum CompositeMode {
+    #[default]
+    Any,          // At least one radar detects
+    AtLeast(u8),  // At least N radars detect
+    BestRadar,    // Coloured by the radar providing the best detection
+    Redundancy,   // Coloured by the number of radars: 1, 2 or 3+
+    WithoutRadar(u8), // N-1: coverage left if this radar (network index) fails
+}
+
+impl CompositeMode {
+    pub fn label(&self) -> &'static str {
+        match self {
+            CompositeMode::Any => "Any radar",
+            CompositeMode::AtLeast(_) => "At least N radars",
+            CompositeMode::BestRadar => "Best radar",
+            CompositeMode::Redundancy => "Redundancy (1 / 2 / 3+)",
+            CompositeMode::WithoutRadar(_) => "N-1: without radar",
+        }
+    }
+}
+
+/// Network coverage of one tile, fused from the tiles of every radar
+#[derive(Debug, Clone, PartialEq)]
+pub struct CompositeTile {
+    pub lat_idx: i32,
+    pub lon_idx: i32,
+    pub size: usize,
+    pub radar_count: Vec<u8>,    // Radars detecting each cell
+    pub best_radar: Vec<u8>,     // Index of the best radar in the fused slice, NO_RADAR if none reaches the cell
+    pub best_snr_margin_db: Vec<f32>, // SNR margin of the best radar, NaN if it was not evaluated
+    pub best_pd: Vec<f32>,       // Pd of the best radar, empty if no radar has a detection model
+}
+
+impl CompositeTile {
+    pub fn covered(&self, idx: usize, mode: CompositeMode) -> bool {
+        match mode {
+            CompositeMode::Any | CompositeMode::BestRadar | CompositeMode::Redundancy => self.radar_count[idx] >= 1,
+            CompositeMode::AtLeast(n) => self.radar_count[idx] >= n.max(1),
+            CompositeMode::WithoutRadar(radar) => self.radar_count[idx] >= 1 && !self.depends_on(idx, radar),
+        }
+    }
+
+    /// The cell is covered by `radar` alone, a single point of failure
+    pub fn depends_on(&self, idx: usize, radar: u8) -> bool {
+        self.radar_count[idx] == 1 && self.best_radar[idx] == radar
+    }
+}
+
+/// A cell counts as detected by a radar when its class is a detection
+pub fn is_detection(class: CoverageClass) -> bool {
+    matches!(class, CoverageClass::Visible | CoverageClass::RangeAmbiguous)
+}
+
+/// Fuse the tiles of several radars over the same area (same size, one per radar,
+/// in a fixed network order so `best_radar` indices agree between tiles).
+///
+/// The best radar of a cell is a detecting one if any, then the highest Pd, then
+/// the highest SNR margin. Cells no radar detects keep the radar with the best class
+/// (see `CoverageClass::merge_rank`), or NO_RADAR when all are out of range.
+pub fn composite_coverage_tiles(tiles: &[&CoverageTile]) -> Option<CompositeTile> {
+    let first = tiles.first()?;
+    let len = first.data.len();
+    let with_pd = tiles.iter().any(|t| !t.pd.is_empty());
+
+    let mut composite = CompositeTile {
+        lat_idx: first.lat_idx,
+        lon_idx: first.lon_idx,
+        size: first.size,
+        radar_count: vec![0; len],
+        best_radar: vec![NO_RADAR; len],
+        best_snr_margin_db: vec![f32::NAN; len],
+        best_pd: if with_pd { vec![0.0; len] } else { Vec::new() },
+    };
+
+    for idx in 0..len {
+        // (detects, class rank, pd, margin) of the best radar so far
+        let mut best: Option<(bool, u8, f32, f32)> = None;
+        for (i, tile) in tiles.iter().enumerate() {
+            let class = CoverageClass::from_u8(tile.data[idx]);
+            if class == CoverageClass::OutOfRange {
+                continue;
+            }
+            let detects = is_detection(class);
+            if detects {
+                composite.radar_count[idx] = composite.radar_count[idx].saturating_add(1);
+            }
+            let pd = tile.pd.get(idx).copied().unwrap_or(0.0);
+            let margin = tile.snr_margin_db[idx];
+            // A margin that was not evaluated ranks lowest
+            let rank_margin = if margin.is_nan() { f32::NEG_INFINITY } else { margin };
+            let candidate = (detects, if detects { 0 } else { class.merge_rank() }, pd, rank_margin);
+            let better = best.is_none_or(|b| {
+                (candidate.0, candidate.1)
+                    .cmp(&(b.0, b.1))
+                    .then(candidate.2.total_cmp(&b.2))
+                    .then(candidate.3.total_cmp(&b.3))
+                    .is_gt()
+            });
+            if better {
+                best = Some(candidate);
+                composite.best_radar[idx] = i.min(NO_RADAR as usize - 1) as u8;
+                composite.best_snr_margin_db[idx] = margin;
+                if with_pd {
+                    composite.best_pd[idx] = pd;
+                }
+            }
+        }
+    }
+
+    Some(composite)
+}
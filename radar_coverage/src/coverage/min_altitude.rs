@@ -0,0 +1,132 @@
+use std::sync::Arc;
+use crate::coverage::{CoverageClass, CoverageRequest, CoverageTile};
+use crate::geo::LatLon;
+use crate::io::Radar;
+use crate::physics::los::TerrainProvider;
+use crate::physics::refraction::effective_earth_radius;
+use crate::physics::radar_eq::{calculate_snr_db_with_gain, max_detection_range};
+use crate::physics::viewshed::HorizonGrid;
+use crate::terrain::{TerrainManager, SRTM3_SIZE};
+
+/// Highest altitude (m AMSL) searched for a detection
+pub const MIN_ALTITUDE_CEILING_M: f64 = 20_000.0;
+/// Vertical resolution of the search above the terrain horizon
+pub const MIN_ALTITUDE_STEP_M: f64 = 50.0;
+
+/// Altitude search of the minimum visible altitude map
+#[derive(Debug, Clone, Copy)]
+pub struct MinAltitudeSearch {
+    pub ceiling_m: f64, // AMSL
+    pub step_m: f64,
+}
+
+impl Default for MinAltitudeSearch {
+    fn default() -> Self {
+        Self {
+            ceiling_m: MIN_ALTITUDE_CEILING_M,
+            step_m: MIN_ALTITUDE_STEP_M,
+        }
+    }
+}
+
+/// Minimum visible altitude map: for each cell, the lowest altitude at which the
+/// target is both above the terrain horizon and detectable (radar equation,
+/// antenna pattern, coverage limits). Independent of `request.target_altitude`.
+///
+/// The visibility floor comes straight from the viewshed horizon angle; above it
+/// the altitude is raised in `search.step_m` increments until the SNR threshold is met.
+/// Clutter and jamming are not evaluated. Cells are Visible when an altitude
+/// below `search.ceiling_m` exists, Shadowed otherwise, and OutOfRange beyond reach.
+pub fn compute_min_altitude_tile(
+    radar: Radar,
+    terrain_manager: Arc<TerrainManager>,
+    viewshed: Arc<HorizonGrid>,
+    lat_idx: i32,
+    lon_idx: i32,
+    request: &CoverageRequest,
+    search: &MinAltitudeSearch,
+) -> CoverageTile {
+    let step_size = request.step_size.max(1);
+    let full_size = SRTM3_SIZE;
+    let size = full_size.div_ceil(step_size);
+    let step_m = search.step_m.max(1.0);
+
+    let mut data = vec![0; size * size];
+    let mut clearance_deg = vec![f32::NAN; size * size];
+    let mut snr_margin_db = vec![f32::NAN; size * size];
+    let mut min_altitude_amsl = vec![f32::NAN; size * size];
+    let mut ground_amsl = vec![0.0; size * size];
+
+    let signature = request.target.signature(radar.frequency_mhz, request.aspect);
+    let max_range = max_detection_range(&radar, signature.max_rcs_sqm());
+    let required_snr_db = radar.required_snr_db();
+
+    let two_k_r = 2.0 * effective_earth_radius(request.refraction);
+    let h_radar = radar.antenna_altitude_amsl();
+
+    for y in 0..size {
+        for x in 0..size {
+            let orig_y = (y * step_size).min(full_size - 1);
+            let orig_x = (x * step_size).min(full_size - 1);
+            let idx = y * size + x;
+
+            let target_loc = LatLon {
+                latitude: (lat_idx as f64 + 1.0) - (orig_y as f64 / (full_size - 1) as f64),
+                longitude: (lon_idx as f64) + (orig_x as f64 / (full_size - 1) as f64),
+                altitude: 0.0,
+            };
+            let ground_alt = terrain_manager.get_altitude(target_loc);
+            ground_amsl[idx] = ground_alt as f32;
+
+            let (dist, bearing) = crate::physics::los::calculate_geodesic(radar.location, target_loc);
+            if dist > max_range || dist < 0.1 || radar.instrumented_range_m.is_some_and(|max| dist > max) {
+                continue;
+            }
+            let Some(horizon_angle) = viewshed.get_horizon_angle(target_loc) else { continue };
+            if radar.min_range_m.is_some_and(|min| dist < min) {
+                data[idx] = CoverageClass::BelowMinRange as u8;
+                continue;
+            }
+
+            // Altitude whose elevation angle just clears the horizon
+            let curvature_drop = dist * dist / two_k_r;
+            let horizon_alt = h_radar + curvature_drop + dist * (horizon_angle as f64).tan();
+            let mut altitude = horizon_alt.max(ground_alt);
+
+            data[idx] = CoverageClass::Shadowed as u8;
+            while altitude <= search.ceiling_m {
+                let elevation_deg = ((altitude - h_radar - curvature_drop) / dist).atan().to_degrees();
+                if radar.max_elevation_deg.is_some_and(|max| elevation_deg > max) {
+                    break; // Only steeper from here on
+                }
+                let snr = radar.gain_towards_dbi(bearing, elevation_deg).map(|gain| {
+                    let rcs = signature.rcs_sqm(bearing, elevation_deg);
+                    calculate_snr_db_with_gain(&radar, dist, rcs, gain)
+                        - radar.waveform.map_or(0.0, |w| w.eclipsing_loss_db(dist))
+                });
+                if let Some(snr) = snr.filter(|snr| *snr >= required_snr_db) {
+                    // Clearance and margin at the lowest detectable altitude
+                    data[idx] = CoverageClass::Visible as u8;
+                    min_altitude_amsl[idx] = altitude as f32;
+                    clearance_deg[idx] = (elevation_deg - (horizon_angle as f64).to_degrees()) as f32;
+                    snr_margin_db[idx] = (snr - required_snr_db) as f32;
+                    break;
+                }
+                altitude += step_m;
+            }
+        }
+    }
+
+    CoverageTile {
+        lat_idx,
+        lon_idx,
+        size,
+        data,
+        clearance_deg,
+        snr_margin_db,
+        pd: Vec::new(),
+        clutter_limited: Vec::new(),
+        min_altitude_amsl,
+        ground_amsl,
+    }
+}
@@ -4,24 +4,228 @@ use crate::geo::LatLon;
 use crate::io::Radar;
 use crate::terrain::{TerrainManager, SRTM3_SIZE};
 use crate::physics::los::TerrainProvider;
-use crate::physics::radar_eq::max_detection_range;
+use crate::physics::radar_eq::{max_detection_range, calculate_snr_db_with_gain, calculate_jnr_db};
+use crate::io::{Jammer, JammerGeometry};
+use crate::physics::esm::{EsmReceiver, intercept_power_dbm, intercept_range};
+use crate::physics::bistatic::{MultistaticNetwork, bistatic_range_product, calculate_bistatic_snr_db};
+use crate::physics::clutter::{ClutterModel, TerrainClass, grazing_angle_rad};
+use crate::physics::target::{AspectMode, TargetModel, TargetSignature};
+use crate::physics::refraction::{effective_earth_radius, RefractionParams};
+use crate::jobs::JobContext;
 use std::sync::Arc;
+use std::sync::atomic::Ordering;
+
+pub mod vertical;
+pub mod min_altitude;
+pub mod composite;
+pub mod redundancy;
+pub mod volume;
+pub mod statistics;
+
+/// Per-cell classes stored in `CoverageTile::data`
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[repr(u8)]
+pub enum CoverageClass {
+    OutOfRange = 0,
+    Visible = 1,
+    Shadowed = 2,
+    ConeOfSilence = 3,   // Above the radar's maximum elevation
+    BelowMinRange = 4,   // Inside the eclipsed minimum range
+    BeyondInstrumented = 5, // Detectable but past the instrumented range
+    BlindRange = 6,      // Lost to pulse eclipsing
+    RangeAmbiguous = 7,  // Detected beyond the unambiguous range
+    ClutterMasked = 8,   // Above noise but below the signal-to-clutter-plus-noise threshold
+    Jammed = 9,          // Detectable without jamming, lost with it
+}
+
+impl CoverageClass {
+    pub fn from_u8(value: u8) -> Self {
+        match value {
+            1 => CoverageClass::Visible,
+            2 => CoverageClass::Shadowed,
+            3 => CoverageClass::ConeOfSilence,
+            4 => CoverageClass::BelowMinRange,
+            5 => CoverageClass::BeyondInstrumented,
+            6 => CoverageClass::BlindRange,
+            7 => CoverageClass::RangeAmbiguous,
+            8 => CoverageClass::ClutterMasked,
+            9 => CoverageClass::Jammed,
+            _ => CoverageClass::OutOfRange,
+        }
+    }
+
+    /// Preference when several radar positions cover the same cell (higher wins)
+    pub fn merge_rank(self) -> u8 {
+        match self {
+            CoverageClass::OutOfRange => 0,
+            CoverageClass::Shadowed => 1,
+            CoverageClass::BelowMinRange => 2,
+            CoverageClass::ConeOfSilence => 3,
+            CoverageClass::BeyondInstrumented => 4,
+            CoverageClass::BlindRange => 5,
+            CoverageClass::ClutterMasked => 6,
+            CoverageClass::Jammed => 7,
+            CoverageClass::RangeAmbiguous => 8,
+            CoverageClass::Visible => 9,
+        }
+    }
+}
 
 #[derive(Debug, Clone)]
 pub struct CoverageTile {
     pub lat_idx: i32,
     pub lon_idx: i32,
     pub size: usize,
-    pub data: Vec<u8>, // 0 = invisible, 1 = visible
-    pub snr_margin: Vec<f32>, // Optional: detailed SNR margin
+    pub data: Vec<u8>, // CoverageClass per cell
+    pub clearance_deg: Vec<f32>, // Elevation of the target above the masking horizon (negative when shadowed), NaN where not evaluated
+    pub snr_margin_db: Vec<f32>, // SNR above the required SNR (ESM: received power above sensitivity), NaN where not evaluated
+    pub pd: Vec<f32>, // Probability of detection (0 where terrain masks the target), empty if the radar has no detection model
+    pub clutter_limited: Vec<bool>, // Residual clutter exceeds noise, empty without a clutter model
+    pub min_altitude_amsl: Vec<f32>, // Lowest detectable altitude (NaN if none), empty unless a min altitude tile
+    pub ground_amsl: Vec<f32>, // Ground altitude per cell, filled alongside min_altitude_amsl
+}
+
+impl CoverageTile {
+    /// Lowest detectable altitude above ground at `idx`, if any
+    pub fn min_altitude_agl(&self, idx: usize) -> Option<f32> {
+        let amsl = *self.min_altitude_amsl.get(idx)?;
+        (!amsl.is_nan()).then(|| amsl - self.ground_amsl[idx])
+    }
+}
+
+/// Ground area (km², WGS84 ellipsoid) represented by cell `idx` of a `size` × `size`
+/// tile whose south-west corner is (`lat_idx`, lon). Cells are 1/(size-1)° apart and
+/// extend south and east of their sample (its north-west corner); the last row and
+/// column duplicate the neighbouring tiles' first ones and count zero, so a tile
+/// sums to exactly its 1° square.
+pub fn tile_cell_area_km2(lat_idx: i32, size: usize, idx: usize) -> f64 {
+    let (x, y) = (idx % size, idx / size);
+    if size < 2 || x == size - 1 || y == size - 1 {
+        return 0.0;
+    }
+    let step_deg = 1.0 / (size - 1) as f64;
+    let north = lat_idx as f64 + 1.0 - y as f64 * step_deg;
+    crate::geo::ellipsoid_area_km2(north - step_deg, north, 0.0, step_deg)
+}
+
+/// What target and layer altitudes are measured from
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub enum AltitudeReference {
+    #[default]
+    Agl,  // Above the ground under the target
+    Amsl, // Above mean sea level
+    FlightLevel, // Pressure altitude (metres, FL × 100 ft) on the 1013.25 hPa standard setting
+}
+
+impl AltitudeReference {
+    pub fn label(&self) -> &'static str {
+        match self {
+            AltitudeReference::Agl => "AGL",
+            AltitudeReference::Amsl => "AMSL",
+            AltitudeReference::FlightLevel => "Flight level",
+        }
+    }
+
+    /// AMSL altitude of `altitude_m` over ground at `ground_m`. Flight levels are
+    /// corrected to true altitude with the sea-level pressure `qnh_hpa`.
+    pub fn to_amsl(&self, altitude_m: f64, ground_m: f64, qnh_hpa: f64) -> f64 {
+        match self {
+            AltitudeReference::Agl => ground_m + altitude_m,
+            AltitudeReference::Amsl => altitude_m,
+            AltitudeReference::FlightLevel => pressure_altitude_to_amsl(altitude_m, qnh_hpa),
+        }
+    }
+}
+
+/// ICAO standard sea-level pressure (hPa), the setting flight levels are flown on
+pub const STANDARD_PRESSURE_HPA: f64 = 1013.25;
+pub const FEET_TO_M: f64 = 0.3048;
+
+// ISA troposphere: p = p0 (1 - LAPSE_RATIO h)^PRESSURE_EXPONENT
+const ISA_LAPSE_RATIO: f64 = 2.25577e-5; // Lapse rate / sea-level temperature, 1/m
+const ISA_PRESSURE_EXPONENT: f64 = 5.25588; // g M / (R L)
+
+/// Flight level (hundreds of feet) to pressure altitude in metres
+pub fn flight_level_to_m(flight_level: f64) -> f64 {
+    flight_level * 100.0 * FEET_TO_M
+}
+
+pub fn m_to_flight_level(pressure_altitude_m: f64) -> f64 {
+    pressure_altitude_m / (100.0 * FEET_TO_M)
+}
+
+/// Standard-atmosphere pressure (hPa) at a pressure altitude
+pub fn isa_pressure_hpa(pressure_altitude_m: f64) -> f64 {
+    STANDARD_PRESSURE_HPA * (1.0 - ISA_LAPSE_RATIO * pressure_altitude_m).max(0.0).powf(ISA_PRESSURE_EXPONENT)
+}
+
+/// Altitude above mean sea level of a pressure altitude, for a sea-level pressure
+/// `qnh_hpa` (ISA temperatures, troposphere only). Equal to the pressure altitude
+/// at 1013.25 hPa; each hPa above it raises the level by about 8 m.
+pub fn pressure_altitude_to_amsl(pressure_altitude_m: f64, qnh_hpa: f64) -> f64 {
+    let ratio = isa_pressure_hpa(pressure_altitude_m) / qnh_hpa;
+    (1.0 - ratio.powf(1.0 / ISA_PRESSURE_EXPONENT)) / ISA_LAPSE_RATIO
+}
+
+/// Target and environment parameters shared by every tile of a coverage computation
+#[derive(Debug, Clone)]
+pub struct CoverageRequest {
+    pub target: TargetModel,
+    pub aspect: AspectMode,
+    pub target_altitude: f64, // Metres above `altitude_reference` (pressure altitude for flight levels)
+    pub altitude_reference: AltitudeReference,
+    pub qnh_hpa: f64, // Sea-level pressure, converts flight levels to AMSL
+    pub step_size: usize,
+    pub clutter: Option<ClutterModel>,
+    pub jammers: Vec<Jammer>,
+    pub refraction: RefractionParams, // Must match the k-factor the viewsheds were computed with
+}
+
+impl Default for CoverageRequest {
+    fn default() -> Self {
+        Self {
+            target: TargetModel::isotropic("1 m² sphere", 1.0),
+            aspect: AspectMode::default(),
+            target_altitude: 50.0,
+            altitude_reference: AltitudeReference::Agl,
+            qnh_hpa: STANDARD_PRESSURE_HPA,
+            step_size: 2,
+            clutter: None,
+            jammers: Vec::new(),
+            refraction: RefractionParams::default(),
+        }
+    }
+}
+
+impl CoverageRequest {
+    /// Target altitude AMSL over ground at `ground_m`
+    pub fn target_amsl(&self, ground_m: f64) -> f64 {
+        self.altitude_reference.to_amsl(self.target_altitude, ground_m, self.qnh_hpa)
+    }
+
+    /// Progress units of one tile computed with this request: one per row
+    pub fn progress_total(&self) -> u32 {
+        SRTM3_SIZE.div_ceil(self.step_size.max(1)) as u32
+    }
+}
+
+// Stand-off jammer geometry seen from the radar, fixed for a whole tile
+struct StandOffJammer<'a> {
+    jammer: &'a Jammer,
+    bearing_deg: f64,
+    elevation_deg: f64,
+    dist_m: f64,
+    erp_w: f64,
 }
 
 #[derive(Component)]
 pub struct CoverageTask(pub Task<CoverageTile>);
 
 
-use crate::physics::viewshed::Viewshed;
+use crate::physics::viewshed::HorizonGrid;
 
+// Row by row, checking `ctx.cancel` and adding one unit to `ctx.progress` per row.
+// None when cancelled.
 pub fn compute_coverage_tile(
     radar: Radar,
     // terrain_manager is not needed inside if we use precomputed viewshed, 
@@ -31,72 +235,450 @@ pub fn compute_coverage_tile(
     // 1. Calculate angle from Radar to P (considering A_target).
     // 2. If Angle_P > Horizon_Angle(P.lat, P.lon), then Visible.
     terrain_manager: Arc<TerrainManager>, // Needed for ground altitude of target
-    viewshed: Arc<Viewshed>,
+    viewshed: Arc<HorizonGrid>,
     lat_idx: i32,
     lon_idx: i32,
-    target_rcs: f64,
-    target_agl: f64,
-    step_size: usize,
-) -> CoverageTile {
+    request: &CoverageRequest,
+    ctx: &JobContext,
+) -> Option<CoverageTile> {
+    let context = DetectionContext::new(&radar, &viewshed, request);
+    let step_size = request.step_size.max(1);
+
     let full_size = SRTM3_SIZE; // 1201
-    let size = (full_size + step_size - 1) / step_size; 
+    let size = full_size.div_ceil(step_size);
     
     let mut data = vec![0; size * size];
-    let mut snr_margin = vec![0.0; size * size];
+    let mut clearance_deg = vec![f32::NAN; size * size];
+    let mut snr_margin_db = vec![f32::NAN; size * size];
+    let mut pd = if radar.detection.is_some() { vec![0.0; size * size] } else { Vec::new() };
+    let mut clutter_limited = if request.clutter.is_some() { vec![false; size * size] } else { Vec::new() };
+
+    for y in 0..size {
+        if ctx.cancel.is_cancelled() {
+            return None;
+        }
+        for x in 0..size {
+            let target_loc = cell_location(lat_idx, lon_idx, step_size, x, y);
+            // Outside the detection range or the viewshed grid
+            let Some(cell) = context.cell_geometry(&terrain_manager, &viewshed, target_loc) else { continue };
+            let idx = y * size + x;
+            let target_alt = request.target_amsl(cell.ground_alt);
+            // An AMSL or flight level altitude under the terrain
+            if target_alt < cell.ground_alt {
+                data[idx] = CoverageClass::Shadowed as u8;
+                continue;
+            }
+            let detection = context.detect(&cell, target_alt);
+            data[idx] = detection.class as u8;
+            clearance_deg[idx] = detection.clearance_deg;
+            snr_margin_db[idx] = detection.snr_margin_db;
+            if let (Some(p), Some(slot)) = (detection.pd, pd.get_mut(idx)) {
+                *slot = p;
+            }
+            if let Some(slot) = clutter_limited.get_mut(idx) {
+                *slot = detection.clutter_limited;
+            }
+        }
+        ctx.progress.done.fetch_add(1, Ordering::Relaxed);
+    }
+
+    Some(CoverageTile {
+        lat_idx,
+        lon_idx,
+        size,
+        data,
+        clearance_deg,
+        snr_margin_db,
+        pd,
+        clutter_limited,
+        min_altitude_amsl: Vec::new(),
+        ground_amsl: Vec::new(),
+    })
+}
+
+/// Location of cell (x, y) of a tile sampled every `step_size` SRTM3 posts, row 0 to the north
+pub fn cell_location(lat_idx: i32, lon_idx: i32, step_size: usize, x: usize, y: usize) -> LatLon {
+    let full_size = SRTM3_SIZE;
+    let orig_y = (y * step_size).min(full_size - 1);
+    let orig_x = (x * step_size).min(full_size - 1);
+    LatLon {
+        latitude: (lat_idx as f64 + 1.0) - (orig_y as f64 / (full_size - 1) as f64),
+        longitude: (lon_idx as f64) + (orig_x as f64 / (full_size - 1) as f64),
+        altitude: 0.0,
+    }
+}
+
+// Radar, target and environment constants of a detection computation, shared by
+// every cell of a tile and every altitude layer of a volume
+pub(crate) struct DetectionContext<'a> {
+    radar: &'a Radar,
+    request: &'a CoverageRequest,
+    signature: TargetSignature,
+    max_range: f64,
+    required_snr_db: f64,
+    two_k_r: f64, // Refraction curvature drop, effective earth radius k·R
+    h_radar: f64,
+    mti_improvement_lin: f64,
+    stand_off: Vec<StandOffJammer<'a>>,
+}
+
+// Geometry of a ground cell seen from the radar, independent of the target altitude
+pub(crate) struct CellGeometry {
+    pub dist: f64,
+    pub bearing: f64,
+    pub horizon_angle: f32,
+    pub ground_alt: f64,
+}
+
+// Detection of a target at one altitude over one cell
+pub(crate) struct CellDetection {
+    pub class: CoverageClass,
+    pub clearance_deg: f32,
+    pub snr_margin_db: f32,
+    pub pd: Option<f32>,
+    pub clutter_limited: bool,
+}
+
+impl<'a> DetectionContext<'a> {
+    pub(crate) fn new(radar: &'a Radar, viewshed: &HorizonGrid, request: &'a CoverageRequest) -> Self {
+        let signature = request.target.signature(radar.frequency_mhz, request.aspect);
+        let two_k_r = 2.0 * effective_earth_radius(request.refraction);
+        let h_radar = radar.antenna_altitude_amsl();
+
+        // Stand-off jammers only count when they have line of sight to the radar
+        let stand_off: Vec<StandOffJammer> = request.jammers.iter()
+            .filter(|j| j.geometry == JammerGeometry::StandOff)
+            .filter_map(|jammer| {
+                let (dist_m, bearing_deg) = crate::physics::los::calculate_geodesic(radar.location, jammer.location);
+                let drop = (dist_m * dist_m) / two_k_r;
+                let elevation = ((jammer.location.altitude - h_radar - drop) / dist_m.max(0.1)).atan();
+                if viewshed.get_horizon_angle(jammer.location).is_some_and(|h| (elevation as f32) < h) {
+                    return None;
+                }
+                let erp_w = jammer.erp_w * 10.0f64.powf(jammer.antenna.relative_gain_db(bearing_deg + 180.0, -elevation.to_degrees()) / 10.0);
+                Some(StandOffJammer { jammer, bearing_deg, elevation_deg: elevation.to_degrees(), dist_m, erp_w })
+            })
+            .collect();
+
+        Self {
+            radar,
+            request,
+            // Range check (peak gain bubble, refined per cell with the antenna pattern)
+            max_range: max_detection_range(radar, signature.max_rcs_sqm()),
+            signature,
+            required_snr_db: radar.required_snr_db(),
+            two_k_r,
+            h_radar,
+            mti_improvement_lin: 10.0f64.powf(radar.mti_improvement_db / 10.0),
+            stand_off,
+        }
+    }
+
+    /// None beyond the detection range or outside the viewshed grid
+    pub(crate) fn cell_geometry(&self, terrain: &TerrainManager, viewshed: &HorizonGrid, loc: LatLon) -> Option<CellGeometry> {
+        let (dist, bearing) = crate::physics::los::calculate_geodesic(self.radar.location, loc);
+        if dist > self.max_range {
+            return None;
+        }
+        // Horizon angle from the viewshed, terrain height under the target
+        let horizon_angle = viewshed.get_horizon_angle(loc)?;
+        let ground_alt = terrain.get_altitude(loc) as f64;
+        Some(CellGeometry { dist, bearing, horizon_angle, ground_alt })
+    }
+
+    /// Class, margins and Pd of a target at `target_alt` (AMSL) over `cell`
+    pub(crate) fn detect(&self, cell: &CellGeometry, target_alt: f64) -> CellDetection {
+        let radar = self.radar;
+        let (dist, bearing) = (cell.dist, cell.bearing);
+        let mut result = CellDetection {
+            class: CoverageClass::OutOfRange,
+            clearance_deg: f32::NAN,
+            snr_margin_db: f32::NAN,
+            pd: None,
+            clutter_limited: false,
+        };
+
+        // Angle to target, with the drop due to curvature
+        let curvature_drop = (dist * dist) / self.two_k_r;
+        let height_diff = target_alt - self.h_radar - curvature_drop;
+        let target_angle = if dist > 0.1 {
+             (height_diff / dist).atan() as f32
+        } else {
+             std::f32::consts::FRAC_PI_2 // 90 deg (overhead/at radar)
+        };
+        result.clearance_deg = (target_angle - cell.horizon_angle).to_degrees();
+
+        // Radar coverage limits: eclipsed minimum range and cone of silence
+        if radar.min_range_m.is_some_and(|min| dist < min) {
+            result.class = CoverageClass::BelowMinRange;
+            return result;
+        }
+        if radar.max_elevation_deg.is_some_and(|max| target_angle.to_degrees() as f64 > max) {
+            result.class = CoverageClass::ConeOfSilence;
+            return result;
+        }
 
-    let max_range = max_detection_range(&radar, target_rcs);
+        // SNR with the antenna gain toward the target; blanked sectors are not covered
+        let Some(target_gain) = radar.gain_towards_dbi(bearing, target_angle.to_degrees() as f64) else { return result };
+        let target_rcs = self.signature.rcs_sqm(bearing, target_angle.to_degrees() as f64);
+        let snr_db = calculate_snr_db_with_gain(radar, dist, target_rcs, target_gain);
+        result.snr_margin_db = (snr_db - self.required_snr_db) as f32;
+        if snr_db < self.required_snr_db {
+            return result;
+        }
+
+        // Pulse timing: echoes overlapping a transmit pulse are (partially) eclipsed
+        let snr_db = match &radar.waveform {
+            Some(waveform) => snr_db - waveform.eclipsing_loss_db(dist),
+            None => snr_db,
+        };
+        result.snr_margin_db = (snr_db - self.required_snr_db) as f32;
+        if snr_db < self.required_snr_db {
+            result.class = CoverageClass::BlindRange;
+            return result;
+        }
+        if radar.instrumented_range_m.is_some_and(|max| dist > max) {
+            result.class = CoverageClass::BeyondInstrumented;
+            return result;
+        }
+
+        if target_angle < cell.horizon_angle {
+            result.class = CoverageClass::Shadowed;
+            return result;
+        }
 
-    // K factor for refraction curvature drop
-    let k = 4.0/3.0; 
-    let two_k_r = 2.0 * k * crate::geo::EARTH_RADIUS as f64;
+        // Clutter from the ground cell under the target, at the same range
+        let cnr_lin = match &self.request.clutter {
+            Some(clutter) => {
+                let ground_angle = ((cell.ground_alt - self.h_radar - curvature_drop) / dist.max(0.1)).atan();
+                let cnr_db = if ground_angle as f32 >= cell.horizon_angle {
+                    let grazing = grazing_angle_rad(ground_angle, dist, self.two_k_r / 2.0);
+                    let class = TerrainClass::from_altitude(cell.ground_alt);
+                    let sigma_c = clutter.clutter_rcs_sqm(class, grazing, dist, radar.azimuth_beamwidth_deg(), radar.range_resolution_m());
+                    radar.gain_towards_dbi(bearing, ground_angle.to_degrees())
+                        .map(|gain| calculate_snr_db_with_gain(radar, dist, sigma_c, gain))
+                        .map(|cnr| cnr - radar.waveform.map_or(0.0, |w| w.eclipsing_loss_db(dist)))
+                } else {
+                    None // Ground masked by terrain, no clutter return
+                };
+
+                let cnr_lin = cnr_db.map_or(0.0, |cnr_db| 10.0f64.powf(cnr_db / 10.0) / self.mti_improvement_lin);
+                result.clutter_limited = cnr_lin > 1.0;
+                cnr_lin
+            }
+            None => 0.0,
+        };
+
+        // Jamming: stand-off through the beam pointed at the target, self-protection in the main beam
+        let mut jnr_lin = 0.0;
+        for j in &self.stand_off {
+            let gain = radar.gain_dbi + radar.antenna.relative_gain_steered_db(bearing, j.bearing_deg, j.elevation_deg);
+            jnr_lin += 10.0f64.powf(calculate_jnr_db(radar, j.jammer, j.erp_w, j.dist_m, gain) / 10.0);
+        }
+        for jammer in self.request.jammers.iter().filter(|j| j.geometry == JammerGeometry::SelfProtection) {
+            jnr_lin += 10.0f64.powf(calculate_jnr_db(radar, jammer, jammer.erp_w, dist, target_gain) / 10.0);
+        }
+
+        // Signal-to-clutter-plus-noise, then signal-to-interference-plus-noise.
+        // Margin and Pd follow the SNR that decides the class.
+        let scnr_db = snr_db - 10.0 * (1.0 + cnr_lin).log10();
+        let sinr_db = snr_db - 10.0 * (1.0 + cnr_lin + jnr_lin).log10();
+        let (class, snr_db) = if scnr_db < self.required_snr_db {
+            (CoverageClass::ClutterMasked, scnr_db)
+        } else if sinr_db < self.required_snr_db {
+            (CoverageClass::Jammed, sinr_db)
+        } else if radar.waveform.is_some_and(|w| w.is_range_ambiguous(dist)) {
+            (CoverageClass::RangeAmbiguous, sinr_db)
+        } else {
+            (CoverageClass::Visible, sinr_db)
+        };
+        result.class = class;
+        result.snr_margin_db = (snr_db - self.required_snr_db) as f32;
+        result.pd = radar.probability_of_detection(snr_db).map(|p| p as f32);
+        result
+    }
+}
+
+/// Intercept coverage of a radar's emissions by an ESM receiver flying at
+/// `request.target_altitude`. Cells are Visible where the one-way signal exceeds the
+/// receiver sensitivity with terrain line of sight, Shadowed where only terrain
+/// prevents the intercept.
+pub fn compute_intercept_tile(
+    radar: Radar,
+    terrain_manager: Arc<TerrainManager>,
+    viewshed: Arc<HorizonGrid>,
+    lat_idx: i32,
+    lon_idx: i32,
+    request: &CoverageRequest,
+    receiver: &EsmReceiver,
+) -> CoverageTile {
+    let step_size = request.step_size.max(1);
+    let full_size = SRTM3_SIZE;
+    let size = full_size.div_ceil(step_size);
+
+    let mut data = vec![0; size * size];
+    let mut clearance_deg = vec![f32::NAN; size * size];
+    let mut snr_margin_db = vec![f32::NAN; size * size];
+
+    let max_range = intercept_range(&radar, receiver);
+    let two_k_r = 2.0 * effective_earth_radius(request.refraction);
+    let h_radar = radar.antenna_altitude_amsl();
 
     for y in 0..size {
         for x in 0..size {
             let orig_y = (y * step_size).min(full_size - 1);
             let orig_x = (x * step_size).min(full_size - 1);
-            
-            let pixel_lat = (lat_idx as f64 + 1.0) - (orig_y as f64 / (full_size - 1) as f64);
-            let pixel_lon = (lon_idx as f64) + (orig_x as f64 / (full_size - 1) as f64);
-            let target_loc = LatLon {
-                latitude: pixel_lat,
-                longitude: pixel_lon,
-                altitude: 0.0, 
+
+            let receiver_loc = LatLon {
+                latitude: (lat_idx as f64 + 1.0) - (orig_y as f64 / (full_size - 1) as f64),
+                longitude: (lon_idx as f64) + (orig_x as f64 / (full_size - 1) as f64),
+                altitude: 0.0,
             };
 
-            // Range check
-            let (dist, _) = crate::physics::los::calculate_geodesic(radar.location, target_loc);
+            let (dist, bearing) = crate::physics::los::calculate_geodesic(radar.location, receiver_loc);
             if dist > max_range {
                 continue;
             }
 
-            // Get Horizon Angle from Viewshed
-            // We need to look up in the viewshed grid.
-            if let Some(horizon_angle) = viewshed.get_horizon_angle(target_loc) {
-                // Get terrain height for target
-                let ground_alt = terrain_manager.get_altitude(target_loc) as f64;
-                let target_alt = ground_alt + target_agl;
-                
-                // Calculate Angle to Target
-                // Drop due to curvature
-                let curvature_drop = (dist * dist) / two_k_r;
-                let height_diff = target_alt - radar.location.altitude - curvature_drop;
-                
-                // Angle to target
-                let target_angle = if dist > 0.1 {
-                     (height_diff / dist).atan() as f32
-                } else {
-                     std::f32::consts::FRAC_PI_2 // 90 deg (overhead/at radar)
-                };
+            // Same terrain masking as the detection coverage, the link is reciprocal
+            let Some(horizon_angle) = viewshed.get_horizon_angle(receiver_loc) else { continue };
+            let ground_alt = terrain_manager.get_altitude(receiver_loc);
+            let height_diff = request.target_amsl(ground_alt) - h_radar - (dist * dist) / two_k_r;
+            let elevation = if dist > 0.1 { (height_diff / dist).atan() as f32 } else { std::f32::consts::FRAC_PI_2 };
+            clearance_deg[y * size + x] = (elevation - horizon_angle).to_degrees();
 
-                if target_angle >= horizon_angle {
-                    data[y * size + x] = 1;
-                    // Margin: difference in degrees
-                    snr_margin[y * size + x] = (target_angle - horizon_angle).to_degrees();
-                } else {
-                    data[y * size + x] = 2; // Shadowed
-                }
+            let power_dbm = match intercept_power_dbm(&radar, receiver, dist, bearing, elevation.to_degrees() as f64) {
+                Some(p) => p,
+                None => continue,
+            };
+            snr_margin_db[y * size + x] = (power_dbm - receiver.sensitivity_dbm) as f32;
+            if power_dbm < receiver.sensitivity_dbm {
+                continue;
+            }
+
+            if elevation >= horizon_angle {
+                data[y * size + x] = CoverageClass::Visible as u8;
             } else {
-                 // Outside viewshed grid (should match max range check usually)
+                data[y * size + x] = CoverageClass::Shadowed as u8;
+            }
+        }
+    }
+
+    CoverageTile {
+        lat_idx,
+        lon_idx,
+        size,
+        data,
+        clearance_deg,
+        snr_margin_db,
+        pd: Vec::new(),
+        clutter_limited: Vec::new(),
+        min_altitude_amsl: Vec::new(),
+        ground_amsl: Vec::new(),
+    }
+}
+
+// Distance, bearing and elevation angle (rad, effective-earth model) from a site to a target
+fn leg_geometry(site: &Radar, target_loc: LatLon, target_alt: f64, two_k_r: f64) -> (f64, f64, f32) {
+    let (dist, bearing) = crate::physics::los::calculate_geodesic(site.location, target_loc);
+    let height_diff = target_alt - site.antenna_altitude_amsl() - (dist * dist) / two_k_r;
+    let elevation = if dist > 0.1 { (height_diff / dist).atan() as f32 } else { std::f32::consts::FRAC_PI_2 };
+    (dist, bearing, elevation)
+}
+
+// Bisector (bearing deg, elevation deg) of the transmitter and receiver legs
+fn bisector(tx_bearing: f64, tx_elevation: f32, rx_bearing: f64, rx_elevation: f32) -> (f64, f64) {
+    let (ts, tc) = tx_bearing.to_radians().sin_cos();
+    let (rs, rc) = rx_bearing.to_radians().sin_cos();
+    let bearing = (ts + rs).atan2(tc + rc).to_degrees();
+    (bearing, ((tx_elevation + rx_elevation) / 2.0).to_degrees() as f64)
+}
+
+/// Multistatic coverage: a cell is Visible when at least one transmitter/receiver
+/// pair reaches the receiver's required SNR with terrain line of sight on both the
+/// transmitter and receiver legs. `rx_viewsheds` follow `network.receivers`.
+pub fn compute_bistatic_coverage_tile(
+    network: &MultistaticNetwork,
+    terrain_manager: Arc<TerrainManager>,
+    tx_viewshed: Arc<HorizonGrid>,
+    rx_viewsheds: &[Arc<HorizonGrid>],
+    lat_idx: i32,
+    lon_idx: i32,
+    request: &CoverageRequest,
+) -> CoverageTile {
+    let step_size = request.step_size.max(1);
+    let full_size = SRTM3_SIZE;
+    let size = full_size.div_ceil(step_size);
+
+    let mut data = vec![0; size * size];
+    let mut clearance_deg = vec![f32::NAN; size * size];
+    let mut snr_margin_db = vec![f32::NAN; size * size];
+    let has_detection_model = network.receivers.iter().any(|rx| rx.detection.is_some());
+    let mut pd = if has_detection_model { vec![0.0; size * size] } else { Vec::new() };
+
+    let tx = &network.transmitter;
+    let two_k_r = 2.0 * effective_earth_radius(request.refraction);
+    // Monostatic RCS at the bistatic bisector, a fair approximation away from forward scatter
+    let signature = request.target.signature(tx.frequency_mhz, request.aspect);
+    let range_products: Vec<f64> = network.receivers.iter()
+        .map(|rx| bistatic_range_product(tx, rx, signature.max_rcs_sqm()))
+        .collect();
+
+    for y in 0..size {
+        for x in 0..size {
+            let orig_y = (y * step_size).min(full_size - 1);
+            let orig_x = (x * step_size).min(full_size - 1);
+
+            let target_loc = LatLon {
+                latitude: (lat_idx as f64 + 1.0) - (orig_y as f64 / (full_size - 1) as f64),
+                longitude: (lon_idx as f64) + (orig_x as f64 / (full_size - 1) as f64),
+                altitude: 0.0,
+            };
+            let target_alt = request.target_amsl(terrain_manager.get_altitude(target_loc));
+
+            // Transmitter leg
+            let (tx_dist, tx_bearing, tx_elevation) = leg_geometry(tx, target_loc, target_alt, two_k_r);
+            let Some(tx_gain) = tx.gain_towards_dbi(tx_bearing, tx_elevation.to_degrees() as f64) else { continue };
+            let tx_clearance = tx_viewshed.get_horizon_angle(target_loc).map(|h| tx_elevation - h);
+
+            let mut best: Option<(f64, f32, &Radar)> = None; // (SNR, clearance, receiver)
+            let mut masked = false;
+            for ((rx, rx_viewshed), range_product) in network.receivers.iter().zip(rx_viewsheds).zip(&range_products) {
+                let (rx_dist, rx_bearing, rx_elevation) = leg_geometry(rx, target_loc, target_alt, two_k_r);
+                // Cheap Cassini-oval rejection with peak gains
+                if tx_dist * rx_dist > *range_product {
+                    continue;
+                }
+                let Some(rx_gain) = rx.gain_towards_dbi(rx_bearing, rx_elevation.to_degrees() as f64) else { continue };
+                let (bisector_bearing, bisector_elevation) = bisector(tx_bearing, tx_elevation, rx_bearing, rx_elevation);
+                let target_rcs = signature.rcs_sqm(bisector_bearing, bisector_elevation);
+                let snr_db = calculate_bistatic_snr_db(tx, rx, tx_dist, rx_dist, target_rcs, tx_gain, rx_gain);
+                if snr_db < rx.required_snr_db() {
+                    continue;
+                }
+
+                // Both legs need terrain line of sight
+                let rx_clearance = rx_viewshed.get_horizon_angle(target_loc).map(|h| rx_elevation - h);
+                match (tx_clearance, rx_clearance) {
+                    (Some(tc), Some(rc)) if tc >= 0.0 && rc >= 0.0 => {
+                        if best.is_none_or(|(best_snr, _, _)| snr_db > best_snr) {
+                            best = Some((snr_db, tc.min(rc), rx));
+                        }
+                    }
+                    _ => masked = true,
+                }
+            }
+
+            let idx = y * size + x;
+            if let Some((snr_db, clearance, rx)) = best {
+                data[idx] = CoverageClass::Visible as u8;
+                clearance_deg[idx] = clearance.to_degrees();
+                snr_margin_db[idx] = (snr_db - rx.required_snr_db()) as f32;
+                if let Some(p) = rx.probability_of_detection(snr_db) {
+                    pd[idx] = p as f32;
+                }
+            } else if masked {
+                data[idx] = CoverageClass::Shadowed as u8;
             }
         }
     }
@@ -106,6 +688,75 @@ pub fn compute_coverage_tile(
         lon_idx,
         size,
         data,
-        snr_margin, 
+        clearance_deg,
+        snr_margin_db,
+        pd,
+        clutter_limited: Vec::new(),
+        min_altitude_amsl: Vec::new(),
+        ground_amsl: Vec::new(),
+    }
+}
+
+/// Union of tiles computed for the same area from several radar positions:
+/// each cell keeps the best class (see `CoverageClass::merge_rank`) and the
+/// best margin and Pd among the positions. All tiles must share the same size.
+pub fn merge_coverage_tiles(tiles: &[CoverageTile]) -> Option<CoverageTile> {
+    let (first, rest) = tiles.split_first()?;
+    let mut merged = first.clone();
+
+    for tile in rest {
+        for idx in 0..merged.data.len() {
+            let current = CoverageClass::from_u8(merged.data[idx]);
+            let candidate = CoverageClass::from_u8(tile.data[idx]);
+            if candidate.merge_rank() > current.merge_rank() {
+                merged.data[idx] = tile.data[idx];
+                merged.clearance_deg[idx] = tile.clearance_deg[idx];
+                merged.snr_margin_db[idx] = tile.snr_margin_db[idx];
+            } else if candidate == current {
+                // f32::max ignores NaN (not evaluated)
+                merged.clearance_deg[idx] = merged.clearance_deg[idx].max(tile.clearance_deg[idx]);
+                merged.snr_margin_db[idx] = merged.snr_margin_db[idx].max(tile.snr_margin_db[idx]);
+            }
+        }
+        if merged.pd.len() == tile.pd.len() {
+            for (p, q) in merged.pd.iter_mut().zip(&tile.pd) {
+                *p = p.max(*q);
+            }
+        }
+        if merged.min_altitude_amsl.len() == tile.min_altitude_amsl.len() {
+            for (m, t) in merged.min_altitude_amsl.iter_mut().zip(&tile.min_altitude_amsl) {
+                // f32::min ignores NaN (never detectable)
+                *m = m.min(*t);
+            }
+        }
+        if merged.clutter_limited.len() == tile.clutter_limited.len() {
+            for (c, d) in merged.clutter_limited.iter_mut().zip(&tile.clutter_limited) {
+                *c = *c && *d;
+            }
+        }
     }
+
+    Some(merged)
+}
+
+/// Coverage of an airborne radar flying its orbit: the tile is computed from each
+/// position in `positions` (see `Radar::orbit_positions`) with the matching
+/// viewshed, then merged. A cell is covered if any point of the orbit sees it.
+/// Reports `request.progress_total()` units per position; None when cancelled.
+pub fn compute_orbit_coverage_tile(
+    positions: &[Radar],
+    terrain_manager: Arc<TerrainManager>,
+    viewsheds: &[Arc<HorizonGrid>],
+    lat_idx: i32,
+    lon_idx: i32,
+    request: &CoverageRequest,
+    ctx: &JobContext,
+) -> Option<CoverageTile> {
+    let tiles: Vec<CoverageTile> = positions.iter()
+        .zip(viewsheds)
+        .map(|(radar, viewshed)| {
+            compute_coverage_tile(radar.clone(), terrain_manager.clone(), viewshed.clone(), lat_idx, lon_idx, request, ctx)
+        })
+        .collect::<Option<_>>()?;
+    merge_coverage_tiles(&tiles)
 }
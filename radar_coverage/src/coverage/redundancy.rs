@@ -0,0 +1,89 @@
+use std::io::Write;
+use std::path::Path;
+use crate::coverage::composite::CompositeTile;
+use crate::coverage::tile_cell_area_km2;
+
+/// Covered area by number of detecting radars, and the single points of failure.
+/// Computed per composite tile, then summed over the network with `add`.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct RedundancySummary {
+    pub single_km2: f64,      // Exactly one radar detects
+    pub double_km2: f64,      // Exactly two
+    pub triple_plus_km2: f64, // Three or more
+    /// Per radar (network order): area only this radar covers, i.e. the coverage
+    /// lost if it fails (N-1)
+    pub sole_km2: Vec<f64>,
+}
+
+impl RedundancySummary {
+    pub fn of_tile(tile: &CompositeTile, n_radars: usize) -> Self {
+        let mut summary = Self { sole_km2: vec![0.0; n_radars], ..Default::default() };
+        for (idx, &count) in tile.radar_count.iter().enumerate() {
+            if count == 0 {
+                continue;
+            }
+            let area = tile_cell_area_km2(tile.lat_idx, tile.size, idx);
+            match count {
+                1 => {
+                    summary.single_km2 += area;
+                    // The only detecting radar is always the best one
+                    if let Some(sole) = summary.sole_km2.get_mut(tile.best_radar[idx] as usize) {
+                        *sole += area;
+                    }
+                }
+                2 => summary.double_km2 += area,
+                _ => summary.triple_plus_km2 += area,
+            }
+        }
+        summary
+    }
+
+    pub fn add(&mut self, other: &Self) {
+        self.single_km2 += other.single_km2;
+        self.double_km2 += other.double_km2;
+        self.triple_plus_km2 += other.triple_plus_km2;
+        if self.sole_km2.len() < other.sole_km2.len() {
+            self.sole_km2.resize(other.sole_km2.len(), 0.0);
+        }
+        for (a, b) in self.sole_km2.iter_mut().zip(&other.sole_km2) {
+            *a += b;
+        }
+    }
+
+    pub fn covered_km2(&self) -> f64 {
+        self.single_km2 + self.double_km2 + self.triple_plus_km2
+    }
+
+    /// Share of the network coverage lost without `radar`, in percent
+    pub fn loss_percent(&self, radar: usize) -> f64 {
+        let covered = self.covered_km2();
+        if covered <= 0.0 {
+            return 0.0;
+        }
+        self.sole_km2.get(radar).copied().unwrap_or(0.0) / covered * 100.0
+    }
+
+    /// Redundancy table then one N-1 line per radar: item,area_km2,percent
+    pub fn write_csv<W: Write>(&self, writer: W, radar_names: &[String]) -> anyhow::Result<()> {
+        let mut csv = csv::Writer::from_writer(writer);
+        csv.write_record(["item", "area_km2", "percent"])?;
+        let covered = self.covered_km2().max(f64::MIN_POSITIVE);
+        for (label, area) in [
+            ("covered by 1 radar", self.single_km2),
+            ("covered by 2 radars", self.double_km2),
+            ("covered by 3+ radars", self.triple_plus_km2),
+        ] {
+            csv.write_record([label.to_string(), format!("{:.1}", area), format!("{:.2}", area / covered * 100.0)])?;
+        }
+        for (radar, area) in self.sole_km2.iter().enumerate() {
+            let name = radar_names.get(radar).map_or("?", String::as_str);
+            csv.write_record([format!("lost without {}", name), format!("{:.1}", area), format!("{:.2}", self.loss_percent(radar))])?;
+        }
+        csv.flush()?;
+        Ok(())
+    }
+
+    pub fn save_csv<P: AsRef<Path>>(&self, path: P, radar_names: &[String]) -> anyhow::Result<()> {
+        self.write_csv(std::fs::File::create(path)?, radar_names)
+    }
+}
@@ -0,0 +1,162 @@
+use std::io::Write;
+use std::path::Path;
+use serde::{Deserialize, Serialize};
+use crate::coverage::composite::is_detection;
+use crate::coverage::{tile_cell_area_km2, CoverageClass, CoverageTile};
+use crate::geo::LatLon;
+use crate::physics::los::calculate_geodesic;
+
+/// Azimuth sectors kept per tile (1°); `regroup` merges them into coarser sectors
+pub const STATISTICS_SECTORS: usize = 360;
+
+/// Area and range statistics of one radar or of the network. Computed per tile,
+/// then summed over the region of interest (the tiles added) with `add`.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct CoverageStatistics {
+    pub tiles: usize,
+    pub region_km2: f64,   // Area of the tiles summed
+    pub covered_km2: f64,  // Detected (Visible or RangeAmbiguous)
+    pub shadowed_km2: f64, // Masked by terrain, and for the network detected by no radar
+    /// Farthest detected cell (m) per azimuth sector around the radar, sector i
+    /// starting at i × 360 / len degrees from true north. Empty for the network.
+    pub sector_range_m: Vec<f64>,
+}
+
+impl CoverageStatistics {
+    /// Statistics of one radar's tile, sectors measured from the radar at `origin`
+    pub fn of_tile(tile: &CoverageTile, origin: LatLon, sectors: usize) -> Self {
+        let mut stats = Self { tiles: 1, sector_range_m: vec![0.0; sectors], ..Default::default() };
+        let step_deg = 1.0 / (tile.size.max(2) - 1) as f64;
+        for (idx, &class) in tile.data.iter().enumerate() {
+            let area = tile_cell_area_km2(tile.lat_idx, tile.size, idx);
+            stats.region_km2 += area;
+            let class = CoverageClass::from_u8(class);
+            if class == CoverageClass::Shadowed {
+                stats.shadowed_km2 += area;
+            }
+            if !is_detection(class) {
+                continue;
+            }
+            stats.covered_km2 += area;
+            if sectors > 0 {
+                // Same cell positions as the texture and `tile_cell_area_km2`
+                let loc = LatLon {
+                    latitude: tile.lat_idx as f64 + 1.0 - (idx / tile.size) as f64 * step_deg,
+                    longitude: tile.lon_idx as f64 + (idx % tile.size) as f64 * step_deg,
+                    altitude: 0.0,
+                };
+                let (dist, bearing) = calculate_geodesic(origin, loc);
+                let sector = ((bearing.rem_euclid(360.0) / 360.0 * sectors as f64) as usize).min(sectors - 1);
+                stats.sector_range_m[sector] = stats.sector_range_m[sector].max(dist);
+            }
+        }
+        stats
+    }
+
+    /// Network statistics of the tiles of several radars over the same area: a cell
+    /// is covered when any radar detects it, shadowed when none does and terrain
+    /// masks it from at least one
+    pub fn of_network(tiles: &[&CoverageTile]) -> Self {
+        let mut stats = Self::default();
+        let Some(first) = tiles.first() else { return stats };
+        stats.tiles = 1;
+        for idx in 0..first.data.len() {
+            let area = tile_cell_area_km2(first.lat_idx, first.size, idx);
+            stats.region_km2 += area;
+            let classes = tiles.iter().map(|t| CoverageClass::from_u8(t.data[idx]));
+            if classes.clone().any(is_detection) {
+                stats.covered_km2 += area;
+            } else if classes.into_iter().any(|c| c == CoverageClass::Shadowed) {
+                stats.shadowed_km2 += area;
+            }
+        }
+        stats
+    }
+
+    pub fn add(&mut self, other: &Self) {
+        self.tiles += other.tiles;
+        self.region_km2 += other.region_km2;
+        self.covered_km2 += other.covered_km2;
+        self.shadowed_km2 += other.shadowed_km2;
+        if self.sector_range_m.len() < other.sector_range_m.len() {
+            self.sector_range_m.resize(other.sector_range_m.len(), 0.0);
+        }
+        for (a, b) in self.sector_range_m.iter_mut().zip(&other.sector_range_m) {
+            *a = a.max(*b);
+        }
+    }
+
+    /// Same statistics with the sector ranges merged into `sectors` wider sectors
+    pub fn regroup(&self, sectors: usize) -> Self {
+        let fine = self.sector_range_m.len();
+        let mut sector_range_m = vec![0.0; if fine > 0 { sectors } else { 0 }];
+        for (i, &range) in self.sector_range_m.iter().enumerate() {
+            let sector = i * sectors / fine;
+            sector_range_m[sector] = f64::max(sector_range_m[sector], range);
+        }
+        Self { sector_range_m, ..self.clone() }
+    }
+
+    pub fn covered_percent(&self) -> f64 {
+        if self.region_km2 > 0.0 { self.covered_km2 / self.region_km2 * 100.0 } else { 0.0 }
+    }
+
+    pub fn shadowed_percent(&self) -> f64 {
+        if self.region_km2 > 0.0 { self.shadowed_km2 / self.region_km2 * 100.0 } else { 0.0 }
+    }
+
+    pub fn max_range_m(&self) -> f64 {
+        self.sector_range_m.iter().copied().fold(0.0, f64::max)
+    }
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct RadarStatistics {
+    pub name: String,
+    pub statistics: CoverageStatistics,
+}
+
+/// Statistics of every radar and of the network, as shown and exported
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct StatisticsReport {
+    pub radars: Vec<RadarStatistics>,
+    pub network: CoverageStatistics,
+}
+
+impl StatisticsReport {
+    /// One line per value: scope,metric,value. Sector ranges are in km, one
+    /// `range_km <from>-<to>` metric per sector.
+    pub fn write_csv<W: Write>(&self, writer: W) -> anyhow::Result<()> {
+        let mut csv = csv::Writer::from_writer(writer);
+        csv.write_record(["scope", "metric", "value"])?;
+        let scopes = self.radars.iter().map(|r| (r.name.as_str(), &r.statistics)).chain([("network", &self.network)]);
+        for (scope, stats) in scopes {
+            for (metric, value) in [
+                ("region_km2", format!("{:.1}", stats.region_km2)),
+                ("covered_km2", format!("{:.1}", stats.covered_km2)),
+                ("covered_percent", format!("{:.2}", stats.covered_percent())),
+                ("shadowed_km2", format!("{:.1}", stats.shadowed_km2)),
+                ("shadowed_percent", format!("{:.2}", stats.shadowed_percent())),
+            ] {
+                csv.write_record([scope, metric, &value])?;
+            }
+            let width = 360.0 / stats.sector_range_m.len().max(1) as f64;
+            for (i, range) in stats.sector_range_m.iter().enumerate() {
+                let metric = format!("range_km {:.0}-{:.0}", i as f64 * width, (i + 1) as f64 * width);
+                csv.write_record([scope, &metric, &format!("{:.1}", range / 1000.0)])?;
+            }
+        }
+        csv.flush()?;
+        Ok(())
+    }
+
+    pub fn save_csv<P: AsRef<Path>>(&self, path: P) -> anyhow::Result<()> {
+        self.write_csv(std::fs::File::create(path)?)
+    }
+
+    pub fn save_json<P: AsRef<Path>>(&self, path: P) -> anyhow::Result<()> {
+        let writer = std::io::BufWriter::new(std::fs::File::create(path)?);
+        serde_json::to_writer_pretty(writer, self)?;
+        Ok(())
+    }
+}
@@ -0,0 +1,164 @@
+use std::io::Write;
+use std::path::Path;
+use crate::coverage::{CoverageClass, CoverageRequest};
+use crate::io::Radar;
+use crate::physics::los::{destination_point, TerrainProvider};
+use crate::physics::radar_eq::calculate_snr_db_with_gain;
+use crate::physics::refraction::effective_earth_radius;
+
+/// Sweep of a vertical coverage (range–height–angle) diagram
+#[derive(Debug, Clone, Copy)]
+pub struct VerticalCoverageParams {
+    pub azimuth_deg: f64,
+    pub max_range_m: f64,
+    pub max_height_m: f64, // AMSL
+    pub range_step_m: f64,
+    pub height_step_m: f64,
+}
+
+impl Default for VerticalCoverageParams {
+    fn default() -> Self {
+        Self {
+            azimuth_deg: 0.0,
+            max_range_m: 200_000.0,
+            max_height_m: 15_000.0,
+            range_step_m: 500.0,
+            height_step_m: 100.0,
+        }
+    }
+}
+
+/// Range–height grid for one radar and azimuth (the "Blake chart").
+/// Cells are stored row-major with row 0 at the lowest height.
+#[derive(Debug, Clone)]
+pub struct VerticalCoverage {
+    pub radar_name: String,
+    pub azimuth_deg: f64,
+    pub ranges_m: Vec<f64>,
+    pub heights_m: Vec<f64>, // AMSL
+    pub terrain_m: Vec<f64>, // Ground altitude under each range sample
+    pub snr_db: Vec<f32>,    // NaN below the ground or outside the antenna sectors
+    pub class: Vec<u8>,      // CoverageClass per cell, OutOfRange below the ground
+}
+
+impl VerticalCoverage {
+    pub fn width(&self) -> usize {
+        self.ranges_m.len()
+    }
+
+    pub fn height(&self) -> usize {
+        self.heights_m.len()
+    }
+
+    pub fn class_at(&self, range_idx: usize, height_idx: usize) -> CoverageClass {
+        CoverageClass::from_u8(self.class[height_idx * self.width() + range_idx])
+    }
+
+    /// One line per cell: range_km,height_m,terrain_m,snr_db,class
+    pub fn write_csv<W: Write>(&self, writer: W) -> anyhow::Result<()> {
+        let mut csv = csv::Writer::from_writer(writer);
+        csv.write_record(["range_km", "height_m", "terrain_m", "snr_db", "class"])?;
+        for (h_idx, height) in self.heights_m.iter().enumerate() {
+            for (r_idx, range) in self.ranges_m.iter().enumerate() {
+                let idx = h_idx * self.width() + r_idx;
+                csv.write_record([
+                    format!("{:.3}", range / 1000.0),
+                    format!("{:.1}", height),
+                    format!("{:.1}", self.terrain_m[r_idx]),
+                    format!("{:.2}", self.snr_db[idx]),
+                    format!("{:?}", CoverageClass::from_u8(self.class[idx])),
+                ])?;
+            }
+        }
+        csv.flush()?;
+        Ok(())
+    }
+
+    pub fn save_csv<P: AsRef<Path>>(&self, path: P) -> anyhow::Result<()> {
+        self.write_csv(std::fs::File::create(path)?)
+    }
+}
+
+/// Range–height coverage along one azimuth, combining the terrain profile,
+/// effective-earth curvature, antenna elevation pattern and radar equation.
+/// Clutter and jamming are not evaluated; terrain masking uses the profile
+/// itself, so no viewshed is needed.
+pub fn compute_vertical_coverage<T: TerrainProvider>(
+    radar: &Radar,
+    terrain: &T,
+    request: &CoverageRequest,
+    params: &VerticalCoverageParams,
+) -> VerticalCoverage {
+    let range_step = params.range_step_m.max(1.0);
+    let height_step = params.height_step_m.max(1.0);
+    let n_ranges = (params.max_range_m / range_step).floor() as usize;
+    let n_heights = (params.max_height_m / height_step).floor() as usize + 1;
+
+    let ranges_m: Vec<f64> = (1..=n_ranges).map(|i| i as f64 * range_step).collect();
+    let heights_m: Vec<f64> = (0..n_heights).map(|i| i as f64 * height_step).collect();
+
+    let two_k_r = 2.0 * effective_earth_radius(request.refraction);
+    let h_radar = radar.antenna_altitude_amsl();
+    let signature = request.target.signature(radar.frequency_mhz, request.aspect);
+    let required_snr_db = radar.required_snr_db();
+
+    // Terrain profile and the masking angle of everything closer than each sample
+    let terrain_m: Vec<f64> = ranges_m.iter()
+        .map(|&range| terrain.get_altitude(destination_point(radar.location, params.azimuth_deg, range)))
+        .collect();
+    let mut horizon = Vec::with_capacity(n_ranges);
+    let mut max_angle = f64::NEG_INFINITY;
+    for (range, ground) in ranges_m.iter().zip(&terrain_m) {
+        horizon.push(max_angle);
+        max_angle = max_angle.max(((ground - h_radar - range * range / two_k_r) / range).atan());
+    }
+
+    let mut snr_db = vec![f32::NAN; n_ranges * n_heights];
+    let mut class = vec![CoverageClass::OutOfRange as u8; n_ranges * n_heights];
+
+    for (h_idx, &height) in heights_m.iter().enumerate() {
+        for (r_idx, &range) in ranges_m.iter().enumerate() {
+            if height < terrain_m[r_idx] {
+                continue; // Underground
+            }
+            let idx = h_idx * n_ranges + r_idx;
+            let elevation = ((height - h_radar - range * range / two_k_r) / range).atan();
+            let elevation_deg = elevation.to_degrees();
+
+            let Some(gain) = radar.gain_towards_dbi(params.azimuth_deg, elevation_deg) else { continue };
+            let rcs = signature.rcs_sqm(params.azimuth_deg, elevation_deg);
+            let snr_free = calculate_snr_db_with_gain(radar, range, rcs, gain);
+            let snr = snr_free - radar.waveform.map_or(0.0, |w| w.eclipsing_loss_db(range));
+            snr_db[idx] = snr as f32;
+
+            // Same precedence as the map coverage
+            class[idx] = if radar.min_range_m.is_some_and(|min| range < min) {
+                CoverageClass::BelowMinRange
+            } else if radar.max_elevation_deg.is_some_and(|max| elevation_deg > max) {
+                CoverageClass::ConeOfSilence
+            } else if snr_free < required_snr_db {
+                CoverageClass::OutOfRange
+            } else if snr < required_snr_db {
+                CoverageClass::BlindRange
+            } else if radar.instrumented_range_m.is_some_and(|max| range > max) {
+                CoverageClass::BeyondInstrumented
+            } else if elevation < horizon[r_idx] {
+                CoverageClass::Shadowed
+            } else if radar.waveform.is_some_and(|w| w.is_range_ambiguous(range)) {
+                CoverageClass::RangeAmbiguous
+            } else {
+                CoverageClass::Visible
+            } as u8;
+        }
+    }
+
+    VerticalCoverage {
+        radar_name: radar.name.clone(),
+        azimuth_deg: params.azimuth_deg,
+        ranges_m,
+        heights_m,
+        terrain_m,
+        snr_db,
+        class,
+    }
+}
@@ -0,0 +1,174 @@
+use std::sync::Arc;
+use std::sync::atomic::Ordering;
+use crate::coverage::{cell_location, AltitudeReference, CoverageClass, CoverageRequest, CoverageTile, DetectionContext};
+use crate::io::Radar;
+use crate::jobs::JobContext;
+use crate::physics::viewshed::HorizonGrid;
+use crate::terrain::{TerrainManager, SRTM3_SIZE};
+
+/// Altitude layers of a coverage volume
+#[derive(Debug, Clone, PartialEq)]
+pub struct CoverageVolumeParams {
+    pub reference: AltitudeReference,
+    pub altitudes_m: Vec<f64>, // Ascending
+}
+
+impl CoverageVolumeParams {
+    /// `count` layers evenly spaced from `lowest_m` to `highest_m`
+    pub fn evenly_spaced(reference: AltitudeReference, lowest_m: f64, highest_m: f64, count: usize) -> Self {
+        let count = count.max(1);
+        let step = if count > 1 { (highest_m - lowest_m) / (count - 1) as f64 } else { 0.0 };
+        Self {
+            reference,
+            altitudes_m: (0..count).map(|i| lowest_m + i as f64 * step).collect(),
+        }
+    }
+}
+
+impl Default for CoverageVolumeParams {
+    fn default() -> Self {
+        Self::evenly_spaced(AltitudeReference::Agl, 100.0, 5_000.0, 10)
+    }
+}
+
+/// Coverage of one tile at a stack of altitudes, one `CoverageTile` per layer
+/// (same cells as a flat tile computed with the same step size).
+#[derive(Debug, Clone)]
+pub struct CoverageVolume {
+    pub lat_idx: i32,
+    pub lon_idx: i32,
+    pub size: usize,
+    pub step_size: usize,
+    pub reference: AltitudeReference,
+    pub altitudes_m: Vec<f64>,
+    pub layers: Vec<CoverageTile>,
+}
+
+/// Coverage at an arbitrary point of a volume. Margins and Pd are interpolated
+/// between the two layers around the altitude, the class is the nearest layer's.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct VolumeSample {
+    pub class: CoverageClass,
+    pub clearance_deg: f32,
+    pub snr_margin_db: f32,
+    pub pd: Option<f32>,
+}
+
+impl CoverageVolume {
+    /// Nearest cell to (lat, lon), None outside the tile
+    pub fn cell_index(&self, lat: f64, lon: f64) -> Option<usize> {
+        let (dlat, dlon) = (lat - self.lat_idx as f64, lon - self.lon_idx as f64);
+        if !(0.0..=1.0).contains(&dlat) || !(0.0..=1.0).contains(&dlon) {
+            return None;
+        }
+        let posts = (SRTM3_SIZE - 1) as f64 / self.step_size as f64;
+        let y = (((1.0 - dlat) * posts).round() as usize).min(self.size - 1);
+        let x = ((dlon * posts).round() as usize).min(self.size - 1);
+        Some(y * self.size + x)
+    }
+
+    /// Coverage at (lat, lon) and `altitude_m` in the volume's reference,
+    /// None outside the tile or outside the span of the layers
+    pub fn sample(&self, lat: f64, lon: f64, altitude_m: f64) -> Option<VolumeSample> {
+        let idx = self.cell_index(lat, lon)?;
+        let (first, last) = (*self.altitudes_m.first()?, *self.altitudes_m.last()?);
+        if !(first..=last).contains(&altitude_m) {
+            return None;
+        }
+        let upper = self.altitudes_m.iter().position(|&a| a >= altitude_m)?;
+        let lower = upper.saturating_sub(1);
+        let span = self.altitudes_m[upper] - self.altitudes_m[lower];
+        let t = if span > 0.0 { ((altitude_m - self.altitudes_m[lower]) / span) as f32 } else { 1.0 };
+
+        let (below, above) = (&self.layers[lower], &self.layers[upper]);
+        let nearest = if t < 0.5 { below } else { above };
+        // Linear between layers, the layer that has it when only one does
+        let lerp = |a: f32, b: f32| match (a.is_nan(), b.is_nan()) {
+            (false, false) => a + (b - a) * t,
+            (true, _) => b,
+            (_, true) => a,
+        };
+        Some(VolumeSample {
+            class: CoverageClass::from_u8(nearest.data[idx]),
+            clearance_deg: lerp(below.clearance_deg[idx], above.clearance_deg[idx]),
+            snr_margin_db: lerp(below.snr_margin_db[idx], above.snr_margin_db[idx]),
+            pd: (!below.pd.is_empty()).then(|| lerp(below.pd[idx], above.pd[idx])),
+        })
+    }
+}
+
+/// Detection coverage of a tile at every altitude of `params`, from one viewshed.
+/// The per-cell geometry (range, bearing, horizon, ground) is computed once and
+/// shared by all layers. With AMSL or flight level layers, altitudes under the
+/// terrain are Shadowed. `request.target_altitude` is ignored; `request.qnh_hpa`
+/// converts flight levels. `tile` is (lat_idx, lon_idx).
+/// Checks `ctx.cancel` and reports progress per row like `compute_coverage_tile`;
+/// None when cancelled.
+pub fn compute_coverage_volume(
+    radar: Radar,
+    terrain_manager: Arc<TerrainManager>,
+    viewshed: Arc<HorizonGrid>,
+    tile: (i32, i32),
+    request: &CoverageRequest,
+    params: &CoverageVolumeParams,
+    ctx: &JobContext,
+) -> Option<CoverageVolume> {
+    let (lat_idx, lon_idx) = tile;
+    let context = DetectionContext::new(&radar, &viewshed, request);
+    let step_size = request.step_size.max(1);
+    let size = SRTM3_SIZE.div_ceil(step_size);
+
+    let empty_layer = || CoverageTile {
+        lat_idx,
+        lon_idx,
+        size,
+        data: vec![0; size * size],
+        clearance_deg: vec![f32::NAN; size * size],
+        snr_margin_db: vec![f32::NAN; size * size],
+        pd: if radar.detection.is_some() { vec![0.0; size * size] } else { Vec::new() },
+        clutter_limited: if request.clutter.is_some() { vec![false; size * size] } else { Vec::new() },
+        min_altitude_amsl: Vec::new(),
+        ground_amsl: Vec::new(),
+    };
+    let mut layers: Vec<CoverageTile> = params.altitudes_m.iter().map(|_| empty_layer()).collect();
+
+    for y in 0..size {
+        if ctx.cancel.is_cancelled() {
+            return None;
+        }
+        for x in 0..size {
+            let target_loc = cell_location(lat_idx, lon_idx, step_size, x, y);
+            let Some(cell) = context.cell_geometry(&terrain_manager, &viewshed, target_loc) else { continue };
+            let idx = y * size + x;
+
+            for (layer, &altitude) in layers.iter_mut().zip(&params.altitudes_m) {
+                let target_alt = params.reference.to_amsl(altitude, cell.ground_alt, request.qnh_hpa);
+                if target_alt < cell.ground_alt {
+                    layer.data[idx] = CoverageClass::Shadowed as u8;
+                    continue;
+                }
+                let detection = context.detect(&cell, target_alt);
+                layer.data[idx] = detection.class as u8;
+                layer.clearance_deg[idx] = detection.clearance_deg;
+                layer.snr_margin_db[idx] = detection.snr_margin_db;
+                if let (Some(p), Some(slot)) = (detection.pd, layer.pd.get_mut(idx)) {
+                    *slot = p;
+                }
+                if let Some(slot) = layer.clutter_limited.get_mut(idx) {
+                    *slot = detection.clutter_limited;
+                }
+            }
+        }
+        ctx.progress.done.fetch_add(1, Ordering::Relaxed);
+    }
+
+    Some(CoverageVolume {
+        lat_idx,
+        lon_idx,
+        size,
+        step_size,
+        reference: params.reference,
+        altitudes_m: params.altitudes_m.clone(),
+        layers,
+    })
+}
@@ -4,6 +4,7 @@ use std::f64::consts::PI;
 use serde::{Serialize, Deserialize};
 
 pub const EARTH_RADIUS: f64 = 6378137.0;
+pub const WGS84_FLATTENING: f64 = 1.0 / 298.257223563;
 
 #[derive(Debug, Clone, Copy, PartialEq, Default, Serialize, Deserialize)]
 pub struct LatLon {
@@ -46,3 +47,16 @@ pub fn webmercator_to_latlon(coord: WebMercator) -> LatLon {
 pub fn get_scale_factor_at_lat(latitude: f64) -> f64 {
     1.0 / latitude.to_radians().cos()
 }
+
+/// Area (km²) of the WGS84 ellipsoid between two parallels and two meridians (degrees).
+/// Exact: the authalic latitude function integrated over the band.
+pub fn ellipsoid_area_km2(south: f64, north: f64, west: f64, east: f64) -> f64 {
+    let e2 = WGS84_FLATTENING * (2.0 - WGS84_FLATTENING);
+    let e = e2.sqrt();
+    let q = |latitude: f64| {
+        let s = latitude.to_radians().sin();
+        s / (1.0 - e2 * s * s) + ((1.0 + e * s) / (1.0 - e * s)).ln() / (2.0 * e)
+    };
+    let a_km = EARTH_RADIUS / 1000.0;
+    a_km * a_km * (1.0 - e2) / 2.0 * (east - west).to_radians() * (q(north) - q(south))
+}
@@ -1,10 +1,15 @@
 use bevy::prelude::*;
 use serde::{Deserialize, Serialize};
 use crate::geo::LatLon;
+use crate::physics::detection::DetectionParams;
+use crate::physics::antenna::{AntennaPattern, PatternShape, PatternTable, in_azimuth_sector};
+use crate::physics::pulse::PulseWaveform;
+use crate::physics::target::{TargetModel, builtin_targets};
 use std::hash::{Hash, Hasher};
 use std::collections::hash_map::DefaultHasher;
+use std::path::{Path, PathBuf};
 
-#[derive(Debug, Clone, Serialize, Deserialize, Component)]
+#[derive(Debug, Clone, Default, Serialize, Deserialize, Component)]
 pub struct Radar {
     pub name: String,
     pub location: LatLon,
@@ -16,26 +21,299 @@ pub struct Radar {
     pub snr_threshold_db: f64,   // dB
     pub azimuth_sector: Option<(f64, f64)>, // min/max degrees
     pub elevation_sector: Option<(f64, f64)>, // min/max degrees
+    #[serde(default)]
+    pub detection: Option<DetectionParams>, // Pd/Pfa requirement, overrides snr_threshold_db
+    #[serde(default)]
+    pub antenna: AntennaPattern, // Relative pattern around gain_dbi
+    #[serde(default)]
+    pub azimuth_pattern_csv: Option<PathBuf>, // Tabulated azimuth cut, replaces antenna.azimuth on load
+    #[serde(default)]
+    pub elevation_pattern_csv: Option<PathBuf>, // Tabulated elevation cut, replaces antenna.elevation on load
+    #[serde(default)]
+    pub min_range_m: Option<f64>, // Pulse eclipsing / receiver recovery
+    #[serde(default)]
+    pub max_elevation_deg: Option<f64>, // Upper coverage limit (cone of silence above)
+    #[serde(default)]
+    pub instrumented_range_m: Option<f64>, // Display / processing range limit
+    #[serde(default)]
+    pub waveform: Option<PulseWaveform>, // PRF / pulse width, None for an ideal unambiguous radar
+    #[serde(default)]
+    pub mti_improvement_db: f64, // MTI / Doppler clutter improvement factor
+    #[serde(default)]
+    pub platform: Platform,
+}
+
+/// Racetrack orbit: two straight legs of `length_m` joined by half-circle turns
+/// of diameter `width_m`, centred on `center` with legs along `heading_deg`.
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct Orbit {
+    pub center: LatLon,
+    pub heading_deg: f64,
+    pub length_m: f64,
+    pub width_m: f64,
+}
+
+impl Orbit {
+    /// `n` positions evenly spaced along the racetrack perimeter
+    pub fn sample_positions(&self, n: usize) -> Vec<LatLon> {
+        let n = n.max(1);
+        let radius = self.width_m / 2.0;
+        let half_length = self.length_m / 2.0;
+        let turn_length = std::f64::consts::PI * radius;
+        let perimeter = 2.0 * self.length_m + 2.0 * turn_length;
+        let heading = self.heading_deg.to_radians();
+
+        (0..n)
+            .map(|i| {
+                let s = perimeter * i as f64 / n as f64;
+                // Local frame: u along the legs, v to the right of the heading
+                let (u, v) = if s < self.length_m {
+                    (-half_length + s, -radius)
+                } else if s < self.length_m + turn_length {
+                    let a = (s - self.length_m) / radius - std::f64::consts::FRAC_PI_2;
+                    (half_length + radius * a.cos(), radius * a.sin())
+                } else if s < 2.0 * self.length_m + turn_length {
+                    (half_length - (s - self.length_m - turn_length), radius)
+                } else {
+                    let a = (s - 2.0 * self.length_m - turn_length) / radius + std::f64::consts::FRAC_PI_2;
+                    (-half_length + radius * a.cos(), radius * a.sin())
+                };
+
+                let north = u * heading.cos() - v * heading.sin();
+                let east = u * heading.sin() + v * heading.cos();
+                LatLon {
+                    latitude: self.center.latitude + north / 111111.0,
+                    longitude: self.center.longitude + east / (111111.0 * self.center.latitude.to_radians().cos()),
+                    altitude: self.center.altitude,
+                }
+            })
+            .collect()
+    }
+}
+
+/// Where the radar antenna is carried
+#[derive(Debug, Clone, PartialEq, Default, Serialize, Deserialize)]
+pub enum Platform {
+    #[default]
+    GroundFixed, // Antenna at location altitude + antenna_height_agl
+    Ship { mast_height_m: f64 }, // Antenna above the sea surface
+    Airborne { altitude_amsl_m: f64, orbit: Option<Orbit> }, // AEW aircraft
+}
+
+impl Platform {
+    pub fn label(&self) -> &'static str {
+        match self {
+            Platform::GroundFixed => "Ground",
+            Platform::Ship { .. } => "Ship",
+            Platform::Airborne { orbit: Some(_), .. } => "Airborne (orbit)",
+            Platform::Airborne { orbit: None, .. } => "Airborne",
+        }
+    }
 }
 
 #[derive(Resource, Default)]
 pub struct RadarList(pub Vec<Radar>);
 
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default, Serialize, Deserialize)]
+pub enum JammingType {
+    #[default]
+    NoiseBarrage, // Spreads its power over a wide band, only a slice falls in the radar receiver
+    Spot,         // Concentrated on the radar frequency
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default, Serialize, Deserialize)]
+pub enum JammerGeometry {
+    #[default]
+    StandOff,       // Fixed position, enters the radar through its sidelobes
+    SelfProtection, // Carried by the target itself, enters through the main beam
+}
+
+/// Electronic attack emitter. For self-protection jammers the location is
+/// ignored: the jammer moves with the evaluated target.
+#[derive(Debug, Clone, Default, Serialize, Deserialize, Component)]
+pub struct Jammer {
+    pub name: String,
+    pub location: LatLon,            // AMSL altitude
+    pub erp_w: f64,                  // Effective radiated power at antenna peak (W)
+    pub bandwidth_hz: f64,           // Jamming noise bandwidth
+    pub center_frequency_mhz: f64,   // Centre of the barrage band (ignored for spot)
+    pub jamming_type: JammingType,
+    pub geometry: JammerGeometry,
+    #[serde(default)]
+    pub antenna: AntennaPattern,     // Relative to erp_w; no boresight means pointed at the radar
+}
+
 impl Radar {
     pub fn get_erps_w(&self) -> f64 {
         let gain_linear = 10.0f64.powf(self.gain_dbi / 10.0);
         let loss_linear = 10.0f64.powf(-self.system_loss_db / 10.0);
         self.tx_power_w * gain_linear * loss_linear
     }
+
+    /// Altitude (m AMSL) of the antenna phase centre, the origin of every LOS computation
+    pub fn antenna_altitude_amsl(&self) -> f64 {
+        match &self.platform {
+            Platform::GroundFixed => self.location.altitude + self.antenna_height_agl,
+            Platform::Ship { mast_height_m } => mast_height_m.max(0.0),
+            Platform::Airborne { altitude_amsl_m, .. } => *altitude_amsl_m,
+        }
+    }
+
+    /// Load the CSV pattern cuts into `antenna`, relative paths resolved from `base_dir`
+    pub fn load_pattern_tables(&mut self, base_dir: &Path) -> anyhow::Result<()> {
+        if let Some(path) = &self.azimuth_pattern_csv {
+            self.antenna.azimuth = PatternShape::Table(PatternTable::from_csv(base_dir.join(path))?);
+        }
+        if let Some(path) = &self.elevation_pattern_csv {
+            self.antenna.elevation = PatternShape::Table(PatternTable::from_csv(base_dir.join(path))?);
+        }
+        Ok(())
+    }
+
+    /// Copy of this radar moved to `location` (e.g. a sampled orbit point)
+    pub fn at_location(&self, location: LatLon) -> Radar {
+        Radar { location, ..self.clone() }
+    }
+
+    /// Radars placed at `n` sampled positions along the airborne orbit,
+    /// or just this radar for fixed platforms.
+    pub fn orbit_positions(&self, n: usize) -> Vec<Radar> {
+        match &self.platform {
+            Platform::Airborne { orbit: Some(orbit), .. } => orbit
+                .sample_positions(n)
+                .into_iter()
+                .map(|loc| self.at_location(loc))
+                .collect(),
+            _ => vec![self.clone()],
+        }
+    }
+
+    /// Average transmitted power (W), equal to the peak power when no waveform is set
+    pub fn average_power_w(&self) -> f64 {
+        match &self.waveform {
+            Some(waveform) => self.tx_power_w * waveform.duty_cycle(),
+            None => self.tx_power_w,
+        }
+    }
+
+    /// Azimuth half-power beamwidth (deg); a full circle for omni antennas
+    pub fn azimuth_beamwidth_deg(&self) -> f64 {
+        self.antenna.azimuth.beamwidth_deg().unwrap_or(360.0)
+    }
+
+    /// Range resolution (m) from the pulse width, or from the default receiver bandwidth
+    pub fn range_resolution_m(&self) -> f64 {
+        match &self.waveform {
+            Some(waveform) => waveform.pulse_range_extent_m(),
+            None => crate::physics::radar_eq::default_range_resolution_m(),
+        }
+    }
+
+    /// Antenna gain (dBi) toward a direction, or None if it falls in a blanked
+    /// sector (outside `azimuth_sector` / `elevation_sector`).
+    pub fn gain_towards_dbi(&self, azimuth_deg: f64, elevation_deg: f64) -> Option<f64> {
+        if let Some(sector) = self.azimuth_sector
+            && !in_azimuth_sector(azimuth_deg, sector)
+        {
+            return None;
+        }
+        if let Some((min_el, max_el)) = self.elevation_sector
+            && (elevation_deg < min_el || elevation_deg > max_el)
+        {
+            return None;
+        }
+        Some(self.gain_dbi + self.antenna.relative_gain_db(azimuth_deg, elevation_deg))
+    }
+
+    /// Single-pulse SNR (dB) a target must reach to count as detected.
+    pub fn required_snr_db(&self) -> f64 {
+        match &self.detection {
+            Some(detection) => detection.required_snr_db(),
+            None => self.snr_threshold_db,
+        }
+    }
+
+    /// Probability of detection at the given SNR, when a detection model is configured.
+    pub fn probability_of_detection(&self, snr_db: f64) -> Option<f64> {
+        self.detection.as_ref().map(|d| d.probability_of_detection(snr_db))
+    }
 }
 
 pub fn load_radars_from_json(path: &str) -> anyhow::Result<Vec<Radar>> {
     let file = std::fs::File::open(path)?;
     let reader = std::io::BufReader::new(file);
-    let radars: Vec<Radar> = serde_json::from_reader(reader)?;
+    let mut radars: Vec<Radar> = serde_json::from_reader(reader)?;
+    // Pattern files are given relative to the radar file
+    let base_dir = Path::new(path).parent().unwrap_or(Path::new("."));
+    for radar in &mut radars {
+        radar.load_pattern_tables(base_dir)
+            .map_err(|e| anyhow::anyhow!("Antenna pattern of {}: {}", radar.name, e))?;
+    }
     Ok(radars)
 }
 
+pub fn load_jammers_from_json(path: &str) -> anyhow::Result<Vec<Jammer>> {
+    let file = std::fs::File::open(path)?;
+    let reader = std::io::BufReader::new(file);
+    let jammers: Vec<Jammer> = serde_json::from_reader(reader)?;
+    Ok(jammers)
+}
+
+/// Target models from one JSON file (an array of `TargetModel`)
+pub fn load_targets_from_json(path: &std::path::Path) -> anyhow::Result<Vec<TargetModel>> {
+    let file = std::fs::File::open(path)?;
+    let reader = std::io::BufReader::new(file);
+    let targets: Vec<TargetModel> = serde_json::from_reader(reader)?;
+    Ok(targets)
+}
+
+/// Built-in targets followed by every `*.json` target file in `dir`.
+/// User models replace built-ins of the same name; unreadable files are skipped.
+pub fn load_target_library(dir: &std::path::Path) -> anyhow::Result<Vec<TargetModel>> {
+    let mut targets = builtin_targets();
+    if !dir.is_dir() {
+        return Ok(targets);
+    }
+
+    let mut paths: Vec<_> = std::fs::read_dir(dir)?
+        .filter_map(|entry| entry.ok().map(|e| e.path()))
+        .filter(|path| path.extension().is_some_and(|ext| ext == "json"))
+        .collect();
+    paths.sort();
+
+    // A malformed file only loses its own targets
+    for path in paths {
+        let loaded = match load_targets_from_json(&path) {
+            Ok(loaded) => loaded,
+            Err(e) => {
+                eprintln!("Skipping target file {:?}: {}", path, e);
+                continue;
+            }
+        };
+        for target in loaded {
+            targets.retain(|t| t.name != target.name);
+            targets.push(target);
+        }
+    }
+    Ok(targets)
+}
+
+pub fn compute_jammer_set_hash(jammers: &[Jammer]) -> u64 {
+    let mut hasher = DefaultHasher::new();
+    for jammer in jammers {
+        jammer.name.hash(&mut hasher);
+        jammer.location.latitude.to_bits().hash(&mut hasher);
+        jammer.location.longitude.to_bits().hash(&mut hasher);
+        jammer.location.altitude.to_bits().hash(&mut hasher);
+        jammer.erp_w.to_bits().hash(&mut hasher);
+        jammer.bandwidth_hz.to_bits().hash(&mut hasher);
+        jammer.center_frequency_mhz.to_bits().hash(&mut hasher);
+        jammer.jamming_type.hash(&mut hasher);
+        jammer.geometry.hash(&mut hasher);
+    }
+    hasher.finish()
+}
+
 pub fn compute_radar_set_hash(radars: &[Radar]) -> u64 {
     let mut hasher = DefaultHasher::new();
     for radar in radars {
@@ -0,0 +1,285 @@
+use std::cmp::Ordering as CmpOrdering;
+use std::collections::HashMap;
+use std::hash::Hash;
+use std::panic::AssertUnwindSafe;
+use std::sync::atomic::{AtomicBool, AtomicU32, Ordering};
+use std::sync::mpsc::{channel, Receiver, Sender};
+use std::sync::{Arc, Mutex};
+use std::time::Duration;
+use bevy::prelude::{Component, Resource};
+
+/// Shared flag checked by long computations between units of work
+#[derive(Debug, Clone, Default)]
+pub struct CancelToken(Arc<AtomicBool>);
+
+impl CancelToken {
+    pub fn cancel(&self) {
+        self.0.store(true, Ordering::Relaxed);
+    }
+
+    pub fn is_cancelled(&self) -> bool {
+        self.0.load(Ordering::Relaxed)
+    }
+}
+
+/// Progress of one job: units done out of a total set by the job itself.
+/// `done` is the counter handed to the viewshed algorithms.
+#[derive(Debug, Clone, Default, Component)]
+pub struct JobProgress {
+    pub done: Arc<AtomicU32>,
+    pub total: Arc<AtomicU32>,
+}
+
+impl JobProgress {
+    pub fn set_total(&self, total: u32) {
+        self.total.store(total, Ordering::Relaxed);
+    }
+
+    pub fn finish(&self) {
+        self.done.store(self.total.load(Ordering::Relaxed), Ordering::Relaxed);
+    }
+
+    /// 0..1, 0 while the total is unknown
+    pub fn fraction(&self) -> f32 {
+        let total = self.total.load(Ordering::Relaxed);
+        if total == 0 {
+            return 0.0;
+        }
+        (self.done.load(Ordering::Relaxed) as f32 / total as f32).clamp(0.0, 1.0)
+    }
+}
+
+/// What a running job sees
+#[derive(Debug, Clone, Default)]
+pub struct JobContext {
+    pub cancel: CancelToken,
+    pub progress: JobProgress,
+}
+
+/// Visible work first, then nearest to the viewer
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct JobPriority {
+    pub visible: bool,
+    pub distance: f32,
+}
+
+impl JobPriority {
+    pub const BACKGROUND: JobPriority = JobPriority { visible: false, distance: f32::INFINITY };
+
+    // Greater is more urgent
+    fn urgency(&self, other: &Self) -> CmpOrdering {
+        self.visible.cmp(&other.visible).then(other.distance.total_cmp(&self.distance))
+    }
+}
+
+/// Counters of one scheduler, for display
+#[derive(Debug, Clone, Copy, Default, PartialEq)]
+pub struct JobStats {
+    pub queued: usize,
+    pub running: usize,
+    pub cancelling: usize, // Cancelled but still running, holding their slot
+    pub completed: u64,
+    pub cancelled: u64,
+    pub progress: f32, // Mean progress of the running jobs
+}
+
+type JobFn<T> = Box<dyn FnOnce(&JobContext) -> Option<T> + Send + Sync>;
+
+struct PendingJob<K, T> {
+    key: K,
+    priority: JobPriority,
+    seq: u64,
+    run: JobFn<T>,
+}
+
+struct RunningJob {
+    generation: u64,
+    context: JobContext,
+}
+
+/// Priority queue of background jobs keyed by `K`, producing `T`.
+///
+/// At most `max_running` jobs run at once, on the rayon pool, so the scheduler
+/// works the same in the Bevy app and headless. Submitting a key that is already
+/// queued or running is a no-op (deduplication). Cancelling a queued job drops it;
+/// cancelling a running one raises its token and discards its result, but the job
+/// keeps its slot until it returns, so cancelled work never oversubscribes the pool.
+/// Call `poll` regularly (every frame) to start queued jobs and collect finished ones.
+#[derive(Resource)]
+pub struct JobScheduler<K, T> {
+    max_running: usize,
+    pending: Vec<PendingJob<K, T>>,
+    running: HashMap<K, RunningJob>,
+    cancelling: HashMap<u64, RunningJob>, // By generation: the key may be resubmitted meanwhile
+    sender: Sender<(K, u64, Option<T>)>,
+    receiver: Mutex<Receiver<(K, u64, Option<T>)>>,
+    next_seq: u64,
+    completed: u64,
+    cancelled: u64,
+}
+
+impl<K, T> JobScheduler<K, T>
+where
+    K: Hash + Eq + Clone + Send + 'static,
+    T: Send + 'static,
+{
+    pub fn new(max_running: usize) -> Self {
+        let (sender, receiver) = channel();
+        Self {
+            max_running: max_running.max(1),
+            pending: Vec::new(),
+            running: HashMap::new(),
+            cancelling: HashMap::new(),
+            sender,
+            receiver: Mutex::new(receiver),
+            next_seq: 0,
+            completed: 0,
+            cancelled: 0,
+        }
+    }
+
+    /// Queue a job; false if the key is already queued or running
+    pub fn submit<F>(&mut self, key: K, priority: JobPriority, run: F) -> bool
+    where
+        F: FnOnce(&JobContext) -> Option<T> + Send + Sync + 'static,
+    {
+        if self.contains(&key) {
+            return false;
+        }
+        self.next_seq += 1;
+        self.pending.push(PendingJob { key, priority, seq: self.next_seq, run: Box::new(run) });
+        true
+    }
+
+    pub fn contains(&self, key: &K) -> bool {
+        self.running.contains_key(key) || self.pending.iter().any(|job| &job.key == key)
+    }
+
+    /// Progress of a running job (the same handle the job reports through)
+    pub fn progress(&self, key: &K) -> Option<JobProgress> {
+        self.running.get(key).map(|job| job.context.progress.clone())
+    }
+
+    /// Recompute the priority of every queued job, e.g. after the camera moved
+    pub fn reprioritize(&mut self, mut priority: impl FnMut(&K) -> JobPriority) {
+        for job in &mut self.pending {
+            job.priority = priority(&job.key);
+        }
+    }
+
+    pub fn cancel(&mut self, key: &K) {
+        self.cancel_where(|k| k == key);
+    }
+
+    /// Cancel every queued or running job whose key matches
+    pub fn cancel_where(&mut self, mut matches: impl FnMut(&K) -> bool) {
+        let before = self.pending.len();
+        self.pending.retain(|job| !matches(&job.key));
+        self.cancelled += (before - self.pending.len()) as u64;
+
+        let keys: Vec<K> = self.running.keys().filter(|k| matches(k)).cloned().collect();
+        for key in keys {
+            if let Some(job) = self.running.remove(&key) {
+                job.context.cancel.cancel();
+                self.cancelled += 1;
+                self.cancelling.insert(job.generation, job);
+            }
+        }
+    }
+
+    pub fn cancel_all(&mut self) {
+        self.cancel_where(|_| true);
+    }
+
+    /// Collect finished jobs, then start the most urgent queued ones
+    pub fn poll(&mut self) -> Vec<(K, T)> {
+        let mut finished = Vec::new();
+        {
+            let receiver = self.receiver.lock().unwrap();
+            while let Ok((key, generation, result)) = receiver.try_recv() {
+                // A cancelled job may finish after its key was resubmitted: match the generation
+                if self.cancelling.remove(&generation).is_some() {
+                    continue;
+                }
+                if self.running.get(&key).is_some_and(|job| job.generation == generation) {
+                    self.running.remove(&key);
+                    if let Some(result) = result {
+                        self.completed += 1;
+                        finished.push((key, result));
+                    }
+                }
+            }
+        }
+
+        while self.running.len() + self.cancelling.len() < self.max_running && !self.pending.is_empty() {
+            let best = (0..self.pending.len())
+                .max_by(|&a, &b| {
+                    let (a, b) = (&self.pending[a], &self.pending[b]);
+                    a.priority.urgency(&b.priority).then(b.seq.cmp(&a.seq))
+                })
+                .unwrap();
+            let job = self.pending.swap_remove(best);
+            let context = JobContext::default();
+            self.running.insert(job.key.clone(), RunningJob { generation: job.seq, context: context.clone() });
+
+            let sender = self.sender.clone();
+            let (key, generation, run) = (job.key, job.seq, job.run);
+            rayon::spawn(move || {
+                // A panic escaping a rayon task aborts the process, and a job that never
+                // reports back would hold its slot forever: a panicking job yields no result
+                let result = std::panic::catch_unwind(AssertUnwindSafe(|| {
+                    if context.cancel.is_cancelled() { None } else { run(&context) }
+                }))
+                .unwrap_or_else(|_| {
+                    eprintln!("Background job panicked, its result is dropped");
+                    None
+                });
+                let result = result.filter(|_| !context.cancel.is_cancelled());
+                let _ = sender.send((key, generation, result));
+            });
+        }
+        finished
+    }
+
+    /// Nothing queued, and every started job (cancelled or not) has returned
+    pub fn is_idle(&self) -> bool {
+        self.pending.is_empty() && self.running.is_empty() && self.cancelling.is_empty()
+    }
+
+    /// Headless use: run everything queued to completion
+    pub fn wait_all(&mut self) -> Vec<(K, T)> {
+        let mut results = Vec::new();
+        loop {
+            results.extend(self.poll());
+            if self.is_idle() {
+                return results;
+            }
+            std::thread::sleep(Duration::from_millis(1));
+        }
+    }
+
+    pub fn stats(&self) -> JobStats {
+        let progress = if self.running.is_empty() {
+            0.0
+        } else {
+            self.running.values().map(|job| job.context.progress.fraction()).sum::<f32>() / self.running.len() as f32
+        };
+        JobStats {
+            queued: self.pending.len(),
+            running: self.running.len(),
+            cancelling: self.cancelling.len(),
+            completed: self.completed,
+            cancelled: self.cancelled,
+            progress,
+        }
+    }
+}
+
+/// Snapshot of the app's schedulers, shown in the UI
+#[derive(Debug, Clone, Copy, Default, Resource)]
+pub struct JobsOverview {
+    pub terrain: JobStats,
+    pub viewshed: JobStats,
+    pub coverage: JobStats,
+    pub volume: JobStats,
+}
@@ -6,6 +6,7 @@ pub mod coverage;
 pub mod render;
 pub mod ui;
 pub mod cache;
+pub mod jobs;
 
 #[cfg(test)]
 mod tests;
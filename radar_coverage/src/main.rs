@@ -1,18 +1,32 @@
 use bevy::prelude::*;
-use bevy::tasks::AsyncComputeTaskPool;
 use bevy_egui::EguiPlugin;
-use futures_lite::future;
 use std::sync::Arc;
 use std::path::PathBuf;
+use std::collections::{HashMap, HashSet};
 
 use radar_coverage::geo::LatLon;
-use radar_coverage::io::Radar;
+use radar_coverage::io::{Radar, Platform, Orbit, Jammer, JammerGeometry, JammingType, compute_jammer_set_hash, load_jammers_from_json, load_target_library};
 use radar_coverage::terrain::{TerrainManager, TerrainLoader};
 use radar_coverage::physics::refraction::RefractionParams;
+use radar_coverage::physics::pulse::PulseWaveform;
+use radar_coverage::physics::detection::DetectionParams;
+use radar_coverage::physics::clutter::ClutterModel;
+use radar_coverage::physics::esm::EsmReceiver;
+use radar_coverage::physics::target::{AspectMode, TargetLibrary};
+use radar_coverage::physics::antenna::{AntennaPattern, PatternShape};
 // use radar_coverage::render;
-use radar_coverage::render::{create_terrain_mesh, create_coverage_texture};
-use radar_coverage::ui::{MapController, map_control_system, ui_panel_system};
-use radar_coverage::coverage::compute_coverage_tile; 
+use radar_coverage::render::{create_terrain_mesh, create_coverage_field_texture, create_min_altitude_texture, create_composite_texture, CoverageColoring};
+use radar_coverage::ui::{MIN_ALTITUDE_DISPLAY_MAX_M, MapController, CoverageLayer, VerticalCoverageView, HorizonProfileView, RedundancyView, CoverageVolumeView, CoverageStatisticsView, map_control_system, ui_panel_system, vertical_coverage_ui_system, horizon_profile_ui_system, redundancy_ui_system, coverage_volume_ui_system, coverage_statistics_ui_system};
+use radar_coverage::physics::horizon::compute_horizon_profile;
+use radar_coverage::coverage::vertical::compute_vertical_coverage;
+use radar_coverage::coverage::min_altitude::{compute_min_altitude_tile, MinAltitudeSearch};
+use radar_coverage::coverage::{compute_bistatic_coverage_tile, compute_coverage_tile, compute_orbit_coverage_tile, compute_intercept_tile, AltitudeReference, CoverageRequest};
+use radar_coverage::physics::bistatic::{bistatic_range_product, cassini_oval, MultistaticNetwork};
+use radar_coverage::physics::viewshed::HorizonGrid;
+use radar_coverage::coverage::composite::{composite_coverage_tiles, CompositeMode};
+use radar_coverage::coverage::redundancy::RedundancySummary;
+use radar_coverage::coverage::statistics::{CoverageStatistics, RadarStatistics, StatisticsReport, STATISTICS_SECTORS};
+use radar_coverage::coverage::volume::{compute_coverage_volume, CoverageVolume};
 // use radar_coverage::physics::los::{LosSystem, TerrainProvider}; 
 use radar_coverage::cache::{CoverageKey, CoverageMetrics, CoverageCache};
 use std::time::Instant;
@@ -24,39 +38,81 @@ struct TerrainResource(Arc<TerrainManager>);
 struct CoverageChunk {
     lat_idx: i32,
     lon_idx: i32,
-    target_agl: f32,
+    target_altitude: f32,
     radar_hash: u64,
     radar_unique_id: u64, // Stable ID (name hash) to identify ownership
+    tile: Arc<radar_coverage::coverage::CoverageTile>,
 }
 
+// Fused network coverage of one tile, replacing the per-radar quads
 #[derive(Component)]
-struct CoverageTask(pub bevy::tasks::Task<radar_coverage::coverage::CoverageTile>);
+struct CompositeChunk {
+    lat_idx: i32,
+    lon_idx: i32,
+    signature: u64, // Hash of the fused radar hashes, in network order
+    mode: CompositeMode,
+    tile: radar_coverage::coverage::composite::CompositeTile,
+    redundancy: RedundancySummary,
+}
 
-#[derive(Component)]
-struct ComputingCoverage {
-    lat: i32,
-    lon: i32,
-    target_agl: f32, // Store AGL to reconstruct key correctly
-    radar_hash: u64,
+// Stable ID (Name only)
+fn radar_unique_id(name: &str) -> u64 {
+    use std::hash::{Hash, Hasher};
+    let mut hasher = std::collections::hash_map::DefaultHasher::new();
+    name.hash(&mut hasher);
+    hasher.finish()
+}
+
+// Coverage job: the cache key plus the owning radar, to cancel its stale jobs
+#[derive(Clone, Copy, PartialEq, Eq, Hash)]
+struct CoverageJob {
+    key: CoverageKey,
     radar_unique_id: u64,
 }
 
-// Marker for tasks that are running
+// Background jobs, keyed by terrain tile, radar entity and coverage tile
+type TerrainJobs = JobScheduler<(i32, i32), Mesh>;
+type ViewshedJobs = JobScheduler<Entity, Vec<radar_coverage::physics::viewshed::HorizonGrid>>; // Radar position first, then the orbit
+type CoverageJobs = JobScheduler<CoverageJob, radar_coverage::coverage::CoverageTile>;
+type VolumeJobs = JobScheduler<(i32, i32), CoverageVolume>;
+type HorizonJobs = JobScheduler<String, radar_coverage::physics::horizon::HorizonProfile>; // Keyed by radar name
+
+// Coverage volumes keep every layer of every tile: sample coarser than the flat tiles
+const VOLUME_STEP_SIZE: usize = 20;
+
+// Slice of the coverage volume drawn in the scene
 #[derive(Component)]
-struct TerrainLoadingTask {
-    task: bevy::tasks::Task<Option<(i32, i32, Mesh)>>,
-    lat: i32,
-    lon: i32,
+struct VolumeSlice;
+
+const WORLD_SCALE: f32 = 111111.0; // World units per degree
+const COVERAGE_ALTITUDE: f32 = 5000.0; // Height of the coverage overlay quads
+
+// Ground point at the centre of a 1° tile
+fn tile_center(lat: i32, lon: i32) -> Vec3 {
+    Vec3::new((lon as f32 + 0.5) * WORLD_SCALE, 0.0, -(lat as f32 + 0.5) * WORLD_SCALE)
+}
+
+// Work whose point is on screen goes first, then the nearest to the camera
+fn view_priority(camera: Option<(&Camera, &GlobalTransform)>, point: Vec3) -> JobPriority {
+    let Some((camera, transform)) = camera else { return JobPriority::BACKGROUND };
+    let visible = camera.world_to_ndc(transform, point)
+        .is_some_and(|ndc| ndc.x.abs() <= 1.0 && ndc.y.abs() <= 1.0 && (0.0..=1.0).contains(&ndc.z));
+    JobPriority { visible, distance: transform.translation().distance(point) }
 }
 
+// Viewshed at the radar, the inputs it was computed from, and for an airborne radar
+// on an orbit one viewshed per sampled orbit position (`Radar::orbit_positions`)
 #[derive(Component)]
-struct RadarViewshed(Arc<radar_coverage::physics::viewshed::Viewshed>);
+struct RadarViewshed(Arc<radar_coverage::physics::viewshed::HorizonGrid>, ViewshedInputs, Vec<Arc<radar_coverage::physics::viewshed::HorizonGrid>>);
 
-use radar_coverage::physics::viewshed::compute_viewshed;
+// Positions an orbit is sampled at for its viewsheds and coverage
+const ORBIT_SAMPLES: usize = 8;
+
+use radar_coverage::physics::viewshed::{compute_horizon_grid, ViewshedSettings};
 // use radar_coverage::physics::radar_eq::max_detection_range;
-use radar_coverage::physics::viewshed::ViewshedProgress;
+use radar_coverage::jobs::{JobContext, JobPriority, JobProgress, JobScheduler, JobsOverview};
+use radar_coverage::cache::viewshed::{quantize_k_factor, ViewshedKey, ViewshedStore};
 
-use std::sync::atomic::AtomicU32;
 
 fn main() {
     let terrain_manager = TerrainManager::new(
@@ -65,23 +121,49 @@ fn main() {
     );
     let terrain_arc = Arc::new(terrain_manager);
 
+    // User target models (*.json) complement the built-in library
+    let targets = load_target_library(&PathBuf::from("assets/targets")).unwrap_or_else(|e| {
+        eprintln!("Failed to load target library: {}", e);
+        TargetLibrary::default().targets
+    });
+
     App::new()
         .add_plugins(DefaultPlugins)
         .add_plugins(EguiPlugin)
         .add_plugins(bevy::pbr::wireframe::WireframePlugin)
         .init_resource::<MapController>()
         .init_resource::<RefractionParams>()
+        .init_resource::<ClutterModel>()
+        .init_resource::<EsmReceiver>()
+        .insert_resource(TargetLibrary { targets })
+        .init_resource::<VerticalCoverageView>()
+        .init_resource::<ViewshedSettings>()
+        .insert_resource(ViewshedStore::new("cache/viewsheds"))
+        .init_resource::<HorizonProfileView>()
+        .init_resource::<RedundancyView>()
+        .init_resource::<CoverageVolumeView>()
+        .init_resource::<CoverageStatisticsView>()
         .init_resource::<radar_coverage::cache::CoverageCache>()
         .init_resource::<radar_coverage::cache::CoverageMetrics>()
         .insert_resource(TerrainResource(terrain_arc.clone()))
+        .insert_resource(TerrainJobs::new(4))
+        .insert_resource(ViewshedJobs::new(2)) // Each one is parallel internally
+        .insert_resource(CoverageJobs::new(std::thread::available_parallelism().map_or(4, |n| n.get())))
+        .insert_resource(VolumeJobs::new(std::thread::available_parallelism().map_or(4, |n| n.get())))
+        .insert_resource(HorizonJobs::new(1))
+        .init_resource::<JobsOverview>()
         // Load 3 radars at their specific locations
         .init_resource::<radar_coverage::cache::CoverageCache>()
         .init_resource::<radar_coverage::cache::CoverageMetrics>()
         .insert_resource(TerrainResource(terrain_arc.clone()))
-        .add_systems(Startup, (setup, setup_radars))
+        .add_systems(Startup, (setup, setup_radars, setup_jammers))
         .add_systems(Update, (
             map_control_system,
             ui_panel_system, 
+            vertical_coverage_ui_system,
+            update_vertical_coverage,
+            horizon_profile_ui_system,
+            update_horizon_profile,
             simple_terrain_loader,
             update_radar_viewshed,
             handle_viewshed_tasks,
@@ -89,22 +171,37 @@ fn main() {
             draw_radar_gizmos,
             schedule_coverage_tasks,
             handle_coverage_tasks,
+            update_composite_chunks,
+            update_redundancy_report,
+            redundancy_ui_system,
+            refresh_coverage_textures,
+            update_jobs_overview,
+            (coverage_volume_ui_system, schedule_volume_jobs, handle_volume_jobs, update_volume_slices),
+            (update_coverage_statistics, coverage_statistics_ui_system),
 // renedr::update_mesh_visibility,
             // render::update_coverage_texture,
         ))
         .run();
 }
 
+// Tiles (lat_idx, lon_idx) loaded, covered and swept into volumes: 11 x 11 around 45N 5E
+fn coverage_area() -> impl Iterator<Item = (i32, i32)> {
+    const CENTER: (i32, i32) = (45, 5);
+    const RADIUS: i32 = 5;
+    (-RADIUS..=RADIUS).flat_map(|dlat| (-RADIUS..=RADIUS).map(move |dlon| (CENTER.0 + dlat, CENTER.1 + dlon)))
+}
+
 fn setup_radars(mut commands: Commands, terrain_res: Res<TerrainResource>) {
+    // The coastal radar runs a medium PRF: blind and ambiguous ranges within its coverage
     let definitions = vec![
-        ("Lyon Mont Verdun", 45.8511, 4.7933, 626.0),
-        ("Sainte Baume", 43.3164, 5.6835, 1148.0),
-        ("Nice Mont Agel", 43.7411, 7.4208, 1151.0),
+        ("Lyon Mont Verdun", 45.8511, 4.7933, 626.0, None),
+        ("Sainte Baume", 43.3164, 5.6835, 1148.0, None),
+        ("Nice Mont Agel", 43.7411, 7.4208, 1151.0, Some(PulseWaveform { prf_hz: 4_000.0, pulse_width_us: 2.0 })),
     ];
 
     let _terrain_manager = &terrain_res.0;
 
-    for (name, lat, lon, altitude) in definitions {
+    for (name, lat, lon, altitude, waveform) in definitions {
         // Query ground altitude? We trust the definition for now or update it.
         // Actually, let's just spawn them.
         println!("Configuring {}: Lat {}, Lon {}, Alt {} m", name, lat, lon, altitude);
@@ -120,10 +217,58 @@ fn setup_radars(mut commands: Commands, terrain_res: Res<TerrainResource>) {
             snr_threshold_db: 13.0, 
             azimuth_sector: None,
             elevation_sector: None,
+            detection: Some(DetectionParams::default()),
+            // Rotating surveillance antenna with cosecant-squared elevation coverage
+            antenna: AntennaPattern {
+                azimuth: PatternShape::Sinc { beamwidth_deg: 1.5 },
+                elevation: PatternShape::CosecantSquared { beamwidth_deg: 4.5, max_elevation_deg: 40.0 },
+                tilt_deg: 2.0,
+                boresight_azimuth_deg: None,
+            },
+            azimuth_pattern_csv: None,
+            elevation_pattern_csv: None,
+            min_range_m: Some(1_000.0),
+            max_elevation_deg: Some(40.0),
+            instrumented_range_m: Some(470_000.0), // Matches the viewshed extent
+            waveform,
+            mti_improvement_db: 30.0,
+            platform: Platform::GroundFixed,
         });
     }
 }
 
+// Jammers from assets/jammers.json (an array of `Jammer`), else the built-in scenario
+fn setup_jammers(mut commands: Commands) {
+    let path = "assets/jammers.json";
+    let jammers = if std::path::Path::new(path).is_file() {
+        load_jammers_from_json(path).unwrap_or_else(|e| {
+            eprintln!("Failed to load jammers from {}: {}", path, e);
+            default_jammers()
+        })
+    } else {
+        default_jammers()
+    };
+    for jammer in jammers {
+        println!("Configuring jammer {}", jammer.name);
+        commands.spawn(jammer);
+    }
+}
+
+fn default_jammers() -> Vec<Jammer> {
+    // Airborne stand-off escort jammer over the Gulf of Lion.
+    // Only taken into account when jamming is enabled in the UI.
+    vec![Jammer {
+        name: "Gulf of Lion SOJ".to_string(),
+        location: LatLon { latitude: 42.6, longitude: 4.5, altitude: 9000.0 },
+        erp_w: 2000.0,
+        bandwidth_hz: 200_000_000.0,
+        center_frequency_mhz: 3100.0,
+        jamming_type: JammingType::NoiseBarrage,
+        geometry: JammerGeometry::StandOff,
+        antenna: Default::default(),
+    }]
+}
+
 fn setup(mut commands: Commands) {
     // Camera
     // Initial Camera Position based on MapController Default (Lat 45, Lon 5)
@@ -163,118 +308,116 @@ fn setup(mut commands: Commands) {
 
 // Simple system to trigger loading of a 3x3 grid around start
 fn simple_terrain_loader(
-    mut commands: Commands,
     terrain_res: Res<TerrainResource>,
-    loading_tasks: Query<&TerrainLoadingTask>,
+    mut jobs: ResMut<TerrainJobs>,
     existing_chunks: Query<&radar_coverage::terrain::TerrainChunk>,
+    cameras: Query<(&Camera, &GlobalTransform)>,
 ) {
-    let center_lat = 45;
-    let center_lon = 5;
-    let radius = 5; // Reduced from 10 to 5 (11x11 = 121 tiles)
+    let camera = cameras.get_single().ok();
 
-    // Spawn Loop
-    let task_pool = AsyncComputeTaskPool::get();
-    
     // Fixed step for simplified visualization and large scale
     let step = 16; // Low detail to handle 400+ tiles
 
-    for dlat in -radius..=radius {
-        for dlon in -radius..=radius {
-            let lat = center_lat + dlat;
-            let lon = center_lon + dlon;
+    for (lat, lon) in coverage_area() {
+        // Check if exists
+        if existing_chunks.iter().any(|c| c.lat_idx == lat && c.lon_idx == lon) {
+            continue;
+        }
 
-            // Check if exists
-            if existing_chunks.iter().any(|c| c.lat_idx == lat && c.lon_idx == lon) {
-                continue;
-            }
-            // Check if loading
-            if loading_tasks.iter().any(|t| t.lat == lat && t.lon == lon) {
-                continue;
+        // Already queued or loading tiles are deduplicated by the scheduler
+        let terrain_manager = terrain_res.0.clone();
+        jobs.submit((lat, lon), view_priority(camera, tile_center(lat, lon)), move |ctx| {
+            // Two steps: read the tile, then build its mesh (with vertex colors)
+            ctx.progress.set_total(2);
+            let tile = terrain_manager.get_tile(lat, lon).ok()?;
+            ctx.progress.done.fetch_add(1, std::sync::atomic::Ordering::Relaxed);
+            if ctx.cancel.is_cancelled() {
+                return None;
             }
-
-            let terrain_manager = terrain_res.0.clone();
-            let task = task_pool.spawn(async move {
-                let tile_res = terrain_manager.get_tile(lat, lon);
-                if let Ok(tile) = tile_res {
-                    // Use Vertex Colors
-                    let mesh = create_terrain_mesh(&tile, step);
-                    return Some((lat, lon, mesh));
-                }
-                None
-            });
-
-            commands.spawn(TerrainLoadingTask {
-                task,
-                lat,
-                lon,
-            });
-        }
+            let mesh = create_terrain_mesh(&tile, step);
+            ctx.progress.finish();
+            Some(mesh)
+        });
     }
+    jobs.reprioritize(|&(lat, lon)| view_priority(camera, tile_center(lat, lon)));
 }
 
 fn handle_terrain_loading(
     mut commands: Commands,
-    mut tasks: Query<(Entity, &mut TerrainLoadingTask)>,
+    mut jobs: ResMut<TerrainJobs>,
     mut meshes: ResMut<Assets<Mesh>>,
     mut materials: ResMut<Assets<StandardMaterial>>,
 ) {
     let scale = 111111.0;
     let target_step = 16; // Consistent with loader
 
-    for (entity, mut task) in &mut tasks {
-        if let Some(result) = future::block_on(future::poll_once(&mut task.task)) {
-            // Task finished
-            if let Some((lat, lon, mesh)) = result {
-                let mesh_handle = meshes.add(mesh);
-                // Use default material with vertex colors enabled
-                let material_handle = materials.add(StandardMaterial {
-                    base_color: Color::WHITE, // Tint
-                    perceptual_roughness: 1.0, 
-                    // Vertex colors are used if attribute is present and no texture?
-                    // Bevy StandardMaterial supports vertex colors.
-                    ..default()
-                });
+    for ((lat, lon), mesh) in jobs.poll() {
+        let mesh_handle = meshes.add(mesh);
+        // Use default material with vertex colors enabled
+        let material_handle = materials.add(StandardMaterial {
+            base_color: Color::WHITE, // Tint
+            perceptual_roughness: 1.0, 
+            // Vertex colors are used if attribute is present and no texture?
+            // Bevy StandardMaterial supports vertex colors.
+            ..default()
+        });
     
-                let pos_x = (lon as f32) * scale;
-                // N45 means 45..46. HGT row 0 is North (46). 
-                // We want Mesh (0,0) [North-West] to be at Lat 46 (Z = -46).
-                // Mesh Z goes 0..1 (scaled to 1 degree).
-                // So if we spawn at Z = -46 * scale, then v=0 is at -46. v=1 is at -45.
-                // lat is 45. -(lat+1) = -46.
-                let pos_z = -((lat + 1) as f32) * scale;
+        let pos_x = (lon as f32) * scale;
+        // N45 means 45..46. HGT row 0 is North (46). 
+        // We want Mesh (0,0) [North-West] to be at Lat 46 (Z = -46).
+        // Mesh Z goes 0..1 (scaled to 1 degree).
+        // So if we spawn at Z = -46 * scale, then v=0 is at -46. v=1 is at -45.
+        // lat is 45. -(lat+1) = -46.
+        let pos_z = -((lat + 1) as f32) * scale;
     
-                commands.spawn((
-                    Mesh3d(mesh_handle),
-                    MeshMaterial3d(material_handle),
-                    Transform::from_xyz(
-                        pos_x, 
-                        0.0, 
-                        pos_z 
-                    ).with_scale(Vec3::new(scale, 1.0, scale)), // Scale Y was 2.0? Reset to 1.0 for true height.
-                    radar_coverage::terrain::TerrainChunk { lat_idx: lat, lon_idx: lon, lod_step: target_step }, 
-                ));
-            }
-            
-            // Remove task
-            commands.entity(entity).despawn();
-        }
+        commands.spawn((
+            Mesh3d(mesh_handle),
+            MeshMaterial3d(material_handle),
+            Transform::from_xyz(
+                pos_x, 
+                0.0, 
+                pos_z 
+            ).with_scale(Vec3::new(scale, 1.0, scale)), // Scale Y was 2.0? Reset to 1.0 for true height.
+            radar_coverage::terrain::TerrainChunk { lat_idx: lat, lon_idx: lon, lod_step: target_step }, 
+        ));
     }
 }
+
 fn draw_radar_gizmos(
     mut gizmos: Gizmos,
     radars: Query<&Radar>,
+    jammers: Query<&Jammer>,
     controller: Res<MapController>,
+    targets: Res<TargetLibrary>,
 ) {
     if !controller.show_coverage {
         return;
     }
 
     let scale = 111111.0;
+
+    // Bistatic detection contours: the Cassini oval of each transmitter/receiver pair
+    // for the selected target, at peak gains and clear of terrain
+    let transmitter = radars.iter().find(|r| controller.bistatic_transmitter.as_ref() == Some(&r.name));
+    if let (CoverageLayer::Bistatic, Some(tx), Some(target)) = (controller.layer, transmitter, targets.targets.get(controller.target_index)) {
+        let rcs_sqm = target.signature(tx.frequency_mhz, controller.aspect).max_rcs_sqm();
+        for rx in radars.iter().filter(|r| controller.bistatic_receivers.contains(&r.name)) {
+            let range_product = bistatic_range_product(tx, rx, rcs_sqm);
+            for contour in cassini_oval(tx.location, rx.location, range_product, 360) {
+                let points = contour.iter().chain(contour.first()).map(|p| Vec3::new(
+                    p.longitude as f32 * scale,
+                    controller.target_altitude,
+                    -(p.latitude as f32) * scale,
+                ));
+                gizmos.linestrip(points, Color::srgb(0.0, 0.9, 1.0));
+            }
+        }
+    }
     
     for radar in radars.iter() {
         let x = radar.location.longitude as f32 * scale;
         let z = -(radar.location.latitude as f32 * scale);
-        let y = radar.location.altitude as f32; // AMSL
+        let y = radar.antenna_altitude_amsl() as f32; // AMSL, platform-aware
 
         // Draw a red sphere at radar location
         gizmos.sphere(
@@ -290,73 +433,451 @@ fn draw_radar_gizmos(
             Color::srgb(1.0, 0.0, 0.0),
         );
     }
+
+    if controller.jamming_enabled {
+        for jammer in jammers.iter().filter(|j| j.geometry == JammerGeometry::StandOff) {
+            let x = jammer.location.longitude as f32 * scale;
+            let z = -(jammer.location.latitude as f32 * scale);
+            let y = jammer.location.altitude as f32;
+
+            // Orange sphere for stand-off jammers
+            gizmos.sphere(Vec3::new(x, y, z), 2000.0, Color::srgb(1.0, 0.5, 0.0)).resolution(32);
+            gizmos.line(Vec3::new(x, y, z), Vec3::new(x, 0.0, z), Color::srgb(1.0, 0.5, 0.0));
+        }
+    }
+}
+
+// Inputs a radar's viewshed was (or is being) computed from
+#[derive(Component, Clone, Copy, PartialEq)]
+struct ViewshedInputs {
+    location: radar_coverage::geo::LatLon,
+    antenna_altitude_m: f64,
+    orbit: Option<Orbit>,
+    k_factor: f32,
+    settings: ViewshedSettings,
+}
+
+impl ViewshedInputs {
+    fn new(radar: &Radar, k_factor: f32, settings: &ViewshedSettings) -> Self {
+        Self {
+            location: radar.location,
+            antenna_altitude_m: radar.antenna_altitude_amsl(),
+            orbit: radar_orbit(radar),
+            k_factor: quantize_k_factor(k_factor),
+            settings: *settings,
+        }
+    }
+}
+
+// Refraction of coverage computed over a viewshed: k at the viewshed's resolution,
+// so that the terrain mask and the propagation share one earth model
+fn viewshed_refraction(refraction: &RefractionParams) -> RefractionParams {
+    RefractionParams { k_factor: quantize_k_factor(refraction.k_factor as f32) as f64 }
+}
+
+fn radar_orbit(radar: &Radar) -> Option<Orbit> {
+    match radar.platform {
+        Platform::Airborne { orbit, .. } => orbit,
+        _ => None,
+    }
+}
+
+// Reload the stored viewshed of `site` if still valid, else compute and store it.
+// Progress counts `settings.progress_total()` units per viewshed.
+fn load_or_compute_viewshed(
+    site: &Radar,
+    terrain_manager: &TerrainManager,
+    settings: &ViewshedSettings,
+    k: f32,
+    store: &ViewshedStore,
+    ctx: &JobContext,
+) -> Option<radar_coverage::physics::viewshed::HorizonGrid> {
+    let start = Instant::now();
+    let fingerprint = terrain_manager.fingerprint(site.location, settings.range_m);
+    let key = ViewshedKey::new(site, k, settings, fingerprint);
+    if let Some(viewshed) = store.load(&key) {
+        ctx.progress.done.fetch_add(settings.progress_total(), std::sync::atomic::Ordering::Relaxed);
+        println!("Viewshed loaded from {:?} in {:.2?}", store.path(&key), start.elapsed());
+        return Some(viewshed);
+    }
+
+    let viewshed = compute_horizon_grid(
+        site,
+        terrain_manager,
+        settings,
+        k,
+        Some(ctx.progress.done.clone()),
+        Some(&ctx.cancel),
+    );
+    if ctx.cancel.is_cancelled() {
+        return None; // Partial grid
+    }
+    println!("Viewshed computed in {:.2?} ({:.1} MB)", start.elapsed(), viewshed.memory_bytes() as f64 / 1e6);
+    if let Err(e) = store.save(&key, &viewshed) {
+        eprintln!("Failed to store viewshed: {}", e);
+    }
+    Some(viewshed)
 }
 
 fn update_radar_viewshed(
     mut commands: Commands,
     terrain_res: Res<TerrainResource>,
-    radars: Query<(Entity, &Radar, Option<&RadarViewshed>, Option<&ComputingViewshedTask>)>,
+    radars: Query<(Entity, &Radar, Option<&ViewshedInputs>)>,
     refraction: Res<RefractionParams>,
-    mut last_k: Local<f32>,
+    settings: Res<ViewshedSettings>,
+    store: Res<ViewshedStore>,
+    mut jobs: ResMut<ViewshedJobs>,
+    cameras: Query<(&Camera, &GlobalTransform)>,
 ) {
-    let task_pool = AsyncComputeTaskPool::get();
-    
-    // We update last_k just to track it, but we don't trigger re-computation
-    if (*last_k - refraction.k_factor as f32).abs() > 0.1 {
-        *last_k = refraction.k_factor as f32;
+    // Computed with the k the inputs and the store are keyed on
+    let k = quantize_k_factor(refraction.k_factor as f32);
+    let camera = cameras.get_single().ok();
+
+    for (entity, radar, inputs) in radars.iter() {
+        let current = ViewshedInputs::new(radar, k, &settings);
+        match inputs {
+            Some(inputs) if *inputs == current => continue, // Up to date, or being computed
+            Some(_) => {
+                // K-factor, radar geometry or viewshed settings changed: cancel the job in
+                // flight and drop the stale viewshed so coverage waits for the new one
+                println!("Viewshed inputs changed for Radar: {:?}", radar.name);
+                jobs.cancel(&entity);
+                commands.entity(entity).remove::<(RadarViewshed, JobProgress)>();
+            }
+            None => {}
+        }
+
+        println!("Computing Viewshed for Radar: {:?} (K={:.2}, {:?}, {:?})", radar.name, k, settings.grid, settings.algorithm);
+        let terrain_manager = terrain_res.0.clone();
+        let radar_clone = radar.clone();
+        let settings = *settings;
+        let store = store.clone();
+
+        // Progress units depend on the grid and algorithm (azimuths, rays, rows or rings)
+        let total = settings.progress_total();
+        let priority = view_priority(camera, Vec3::new(
+            radar.location.longitude as f32 * WORLD_SCALE,
+            0.0,
+            -(radar.location.latitude as f32) * WORLD_SCALE,
+        ));
+
+        // The radar position, then each sampled position of an airborne orbit
+        let mut sites = vec![radar_clone.clone()];
+        if radar_orbit(&radar_clone).is_some() {
+            sites.extend(radar_clone.orbit_positions(ORBIT_SAMPLES));
+        }
+        jobs.submit(entity, priority, move |ctx| {
+            ctx.progress.set_total(total * sites.len() as u32);
+            sites.iter()
+                .map(|site| load_or_compute_viewshed(site, &terrain_manager, &settings, k, &store, ctx))
+                .collect()
+        });
+
+        commands.entity(entity).insert(current);
     }
+}
 
-    for (entity, radar, viewshed_opt, computing_task) in radars.iter() {
-        // Only compute if viewshed is missing (startup) AND not already computing
-        if viewshed_opt.is_none() && computing_task.is_none() {
-             println!("Computing Viewshed for Radar: {:?} (K={:.2})", radar.name, refraction.k_factor);
-            let terrain_manager = terrain_res.0.clone();
-            let radar_clone = radar.clone();
-            
-            // Calculate approx total rays for progress
-            // width = ceil(range*2 / cell)
-            // perimeter = width*4
-            let range: f64 = 470000.0;
-            let cell: f64 = 100.0;
-            let width = (range * 2.0 / cell).ceil() as u32;
-            let total_rays = width * 4; 
-            
-            let progress = Arc::new(AtomicU32::new(0));
-            let progress_clone = progress.clone();
-            // Use current K factor for initial computation
-            let k = refraction.k_factor as f32;
-
-            // Spawn async task for viewshed computation
-            let task = task_pool.spawn(async move {
-                let start = Instant::now();
-                let viewshed = compute_viewshed(&radar_clone, &terrain_manager, range, k, Some(progress_clone));
-                println!("Viewshed computed in {:.2?}", start.elapsed());
-                viewshed
-            });
-            
-            commands.entity(entity)
-                .insert(ComputingViewshedTask(task))
-                .insert(ViewshedProgress { current: progress, total: total_rays });
+fn handle_viewshed_tasks(
+    mut commands: Commands,
+    mut jobs: ResMut<ViewshedJobs>,
+    radars: Query<&ViewshedInputs>,
+    waiting: Query<Entity, (With<ViewshedInputs>, Without<RadarViewshed>, Without<JobProgress>)>,
+) {
+    for (entity, viewsheds) in jobs.poll() {
+        // The radar may have been despawned meanwhile
+        let Ok(inputs) = radars.get(entity) else { continue };
+        let mut viewsheds = viewsheds.into_iter().map(Arc::new);
+        let Some(viewshed) = viewsheds.next() else { continue };
+        commands.entity(entity)
+            .insert(RadarViewshed(viewshed, *inputs, viewsheds.collect()))
+            .remove::<JobProgress>();
+        println!("Viewshed applied to entity {:?}", entity);
+    }
+    // Expose the progress of newly started jobs to the UI
+    for entity in waiting.iter() {
+        if let Some(progress) = jobs.progress(&entity) {
+            commands.entity(entity).insert(progress);
         }
     }
 }
 
-#[derive(Component)]
-struct ComputingViewshedTask(bevy::tasks::Task<radar_coverage::physics::viewshed::Viewshed>);
+fn update_jobs_overview(
+    terrain: Res<TerrainJobs>,
+    viewshed: Res<ViewshedJobs>,
+    coverage: Res<CoverageJobs>,
+    volume: Res<VolumeJobs>,
+    mut overview: ResMut<JobsOverview>,
+) {
+    *overview = JobsOverview {
+        terrain: terrain.stats(),
+        viewshed: viewshed.stats(),
+        coverage: coverage.stats(),
+        volume: volume.stats(),
+    };
+}
 
-fn handle_viewshed_tasks(
+// Sum the per-tile redundancy of the composite layer while the report is open
+fn update_redundancy_report(
+    mut view: ResMut<RedundancyView>,
+    composites: Query<&CompositeChunk>,
+    radars: Query<&Radar>,
+) {
+    if !view.open {
+        return;
+    }
+    let mut radar_names: Vec<String> = radars.iter().map(|r| r.name.clone()).collect();
+    radar_names.sort();
+    let mut summary = RedundancySummary { sole_km2: vec![0.0; radar_names.len()], ..Default::default() };
+    for composite in composites.iter() {
+        summary.add(&composite.redundancy);
+    }
+    let tiles = composites.iter().count();
+    if view.summary != summary || view.tiles != tiles || view.radar_names != radar_names {
+        *view = RedundancyView { open: true, radar_names, tiles, summary };
+    }
+}
+
+// Statistics of each coverage tile, computed once: per radar chunk (radar hash,
+// lat, lon), and per tile for the network with the set of radar hashes fused
+#[derive(Default)]
+struct StatisticsCache {
+    radar: HashMap<(u64, i32, i32), CoverageStatistics>,
+    network: HashMap<(i32, i32), (u64, CoverageStatistics)>,
+}
+
+// Sum the per-tile statistics of the coverage chunks while the window is open
+fn update_coverage_statistics(
+    mut view: ResMut<CoverageStatisticsView>,
+    chunks: Query<&CoverageChunk>,
+    radars: Query<&Radar>,
+    controller: Res<MapController>,
+    mut cache: Local<StatisticsCache>,
+) {
+    use rayon::prelude::*;
+    use std::hash::{Hash, Hasher};
+
+    if !view.open {
+        return;
+    }
+    // Intercept and minimum altitude tiles mark other things Visible
+    if !controller.layer.detects_target() {
+        if view.report != StatisticsReport::default() {
+            view.report = StatisticsReport::default();
+        }
+        return;
+    }
+    let origins: HashMap<u64, &Radar> = radars.iter().map(|r| (radar_unique_id(&r.name), r)).collect();
+    let chunks: Vec<&CoverageChunk> = chunks.iter().filter(|c| origins.contains_key(&c.radar_unique_id)).collect();
+
+    // New radar tiles, in parallel: a whole coverage area arrives at once when the window opens
+    let live: HashSet<(u64, i32, i32)> = chunks.iter().map(|c| (c.radar_hash, c.lat_idx, c.lon_idx)).collect();
+    cache.radar.retain(|key, _| live.contains(key));
+    let missing: Vec<&&CoverageChunk> = chunks.iter().filter(|c| !cache.radar.contains_key(&(c.radar_hash, c.lat_idx, c.lon_idx))).collect();
+    let computed: Vec<_> = missing.par_iter().map(|c| {
+        let origin = origins[&c.radar_unique_id].location;
+        ((c.radar_hash, c.lat_idx, c.lon_idx), CoverageStatistics::of_tile(&c.tile, origin, STATISTICS_SECTORS))
+    }).collect();
+    cache.radar.extend(computed);
+
+    // Network tiles, recomputed when the set of radar tiles fused there changes
+    let mut by_tile: HashMap<(i32, i32), Vec<&CoverageChunk>> = HashMap::new();
+    for chunk in &chunks {
+        by_tile.entry((chunk.lat_idx, chunk.lon_idx)).or_default().push(chunk);
+    }
+    cache.network.retain(|key, _| by_tile.contains_key(key));
+    let signature = |tile: &[&CoverageChunk]| {
+        let mut hashes: Vec<u64> = tile.iter().map(|c| c.radar_hash).collect();
+        hashes.sort();
+        let mut hasher = std::collections::hash_map::DefaultHasher::new();
+        hashes.hash(&mut hasher);
+        hasher.finish()
+    };
+    let stale: Vec<_> = by_tile.iter()
+        .map(|(key, tile)| (*key, signature(tile), tile))
+        .filter(|(key, sig, _)| cache.network.get(key).is_none_or(|(cached, _)| cached != sig))
+        .collect();
+    let computed: Vec<_> = stale.par_iter().map(|(key, sig, tile)| {
+        let tiles: Vec<&radar_coverage::coverage::CoverageTile> = tile.iter().map(|c| c.tile.as_ref()).collect();
+        (*key, (*sig, CoverageStatistics::of_network(&tiles)))
+    }).collect();
+    cache.network.extend(computed);
+
+    let mut report = StatisticsReport::default();
+    let mut names: Vec<&String> = origins.values().map(|r| &r.name).collect();
+    names.sort();
+    for name in names {
+        let id = radar_unique_id(name);
+        let mut statistics = CoverageStatistics { sector_range_m: vec![0.0; STATISTICS_SECTORS], ..Default::default() };
+        for chunk in chunks.iter().filter(|c| c.radar_unique_id == id) {
+            statistics.add(&cache.radar[&(chunk.radar_hash, chunk.lat_idx, chunk.lon_idx)]);
+        }
+        report.radars.push(RadarStatistics { name: name.clone(), statistics: statistics.regroup(view.sectors) });
+    }
+    for (_, statistics) in cache.network.values() {
+        report.network.add(statistics);
+    }
+    if view.report != report {
+        view.report = report;
+    }
+}
+
+fn schedule_volume_jobs(
+    mut view: ResMut<CoverageVolumeView>,
+    mut jobs: ResMut<VolumeJobs>,
+    radars: Query<(&Radar, Option<&RadarViewshed>)>,
+    jammers: Query<&Jammer>,
+    terrain_res: Res<TerrainResource>,
+    controller: Res<MapController>,
+    targets: Res<TargetLibrary>,
+    clutter: Res<ClutterModel>,
+    refraction: Res<RefractionParams>,
+    viewshed_settings: Res<ViewshedSettings>,
+    cameras: Query<(&Camera, &GlobalTransform)>,
+) {
+    if !view.compute_requested {
+        return;
+    }
+    view.compute_requested = false;
+
+    let Some((radar, viewshed)) = radars.iter().find(|(r, _)| Some(&r.name) == view.radar_name.as_ref()) else { return };
+    let refraction = viewshed_refraction(&refraction);
+    let viewshed = match viewshed {
+        Some(v) if v.1 == ViewshedInputs::new(radar, refraction.k_factor as f32, &viewshed_settings) => v.0.clone(),
+        _ => {
+            println!("Viewshed of {} not ready, volume not computed", radar.name);
+            return;
+        }
+    };
+    let Some(target) = targets.targets.get(controller.target_index) else { return };
+    // Same target and environment as the detection layer
+    let request = CoverageRequest {
+        target: target.clone(),
+        aspect: controller.aspect,
+        qnh_hpa: controller.qnh_hpa as f64,
+        step_size: VOLUME_STEP_SIZE,
+        clutter: controller.clutter_enabled.then(|| clutter.clone()),
+        jammers: if controller.jamming_enabled { jammers.iter().cloned().collect() } else { Vec::new() },
+        refraction,
+        ..Default::default()
+    };
+    let params = view.params();
+
+    jobs.cancel_all();
+    view.volumes.clear();
+    let camera = cameras.get_single().ok();
+    for (lat, lon) in coverage_area() {
+        let (radar, terrain, viewshed, request, params) =
+            (radar.clone(), terrain_res.0.clone(), viewshed.clone(), request.clone(), params.clone());
+        jobs.submit((lat, lon), view_priority(camera, tile_center(lat, lon)), move |ctx| {
+            ctx.progress.set_total(request.progress_total());
+            compute_coverage_volume(radar, terrain, viewshed, (lat, lon), &request, &params, ctx)
+        });
+    }
+}
+
+fn handle_volume_jobs(mut view: ResMut<CoverageVolumeView>, mut jobs: ResMut<VolumeJobs>) {
+    let finished = jobs.poll();
+    let stats = jobs.stats();
+    let pending = stats.queued + stats.running;
+    if !finished.is_empty() || view.pending != pending {
+        view.volumes.extend(finished.into_iter().map(|(_, volume)| volume));
+        view.pending = pending;
+    }
+}
+
+// Draw the selected slice, or every layer, of the computed volume at the layer
+// altitudes (AGL layers at their height above sea level)
+fn update_volume_slices(
     mut commands: Commands,
-    mut tasks: Query<(Entity, &mut ComputingViewshedTask)>,
+    view: Res<CoverageVolumeView>,
+    controller: Res<MapController>,
+    slices: Query<Entity, With<VolumeSlice>>,
+    mut images: ResMut<Assets<Image>>,
+    mut materials: ResMut<Assets<StandardMaterial>>,
+    mut meshes: ResMut<Assets<Mesh>>,
+    mut shown: Local<Option<(usize, usize, bool, bool, CoverageColoring)>>,
+) {
+    let display = (view.volumes.len(), view.slice, view.show_all, view.open, controller.coloring);
+    if *shown == Some(display) {
+        return;
+    }
+    *shown = Some(display);
+
+    for entity in slices.iter() {
+        commands.entity(entity).despawn_recursive();
+    }
+    if !view.open {
+        return;
+    }
+    for volume in &view.volumes {
+        for (i, (layer, &altitude)) in volume.layers.iter().zip(&volume.altitudes_m).enumerate() {
+            if !view.show_all && i != view.slice {
+                continue;
+            }
+            let texture_handle = images.add(create_coverage_field_texture(layer, controller.coloring));
+            commands.spawn((
+                coverage_quad(&mut meshes, &mut materials, texture_handle, volume.lat_idx, volume.lon_idx, altitude as f32),
+                VolumeSlice,
+            ));
+        }
+    }
+}
+
+fn update_vertical_coverage(
+    mut view: ResMut<VerticalCoverageView>,
+    terrain_res: Res<TerrainResource>,
+    radars: Query<&Radar>,
+    controller: Res<MapController>,
+    targets: Res<TargetLibrary>,
+    refraction: Res<RefractionParams>,
 ) {
-     for (entity, mut task) in &mut tasks {
-        if let Some(viewshed) = future::block_on(future::poll_once(&mut task.0)) {
-            commands.entity(entity)
-                .insert(RadarViewshed(Arc::new(viewshed)))
-                .remove::<ComputingViewshedTask>()
-                .remove::<ViewshedProgress>();
-            println!("Viewshed applied to entity {:?}", entity);
+    if !view.compute_requested {
+        return;
+    }
+    view.compute_requested = false;
+
+    let Some(radar) = radars.iter().find(|r| Some(&r.name) == view.radar_name.as_ref()) else { return };
+    let Some(target) = targets.targets.get(controller.target_index) else { return };
+    let request = CoverageRequest {
+        target: target.clone(),
+        aspect: controller.aspect,
+        refraction: viewshed_refraction(&refraction),
+        ..Default::default()
+    };
+
+    let diagram = compute_vertical_coverage(radar, terrain_res.0.as_ref(), &request, &view.params);
+    view.set_diagram(diagram);
+}
+
+fn update_horizon_profile(
+    mut view: ResMut<HorizonProfileView>,
+    mut jobs: ResMut<HorizonJobs>,
+    terrain_res: Res<TerrainResource>,
+    radars: Query<&Radar>,
+    refraction: Res<RefractionParams>,
+) {
+    if view.compute_requested {
+        view.compute_requested = false;
+        if let Some(radar) = radars.iter().find(|r| Some(&r.name) == view.radar_name.as_ref()) {
+            // Only the latest request matters
+            jobs.cancel_all();
+            let radar = radar.clone();
+            let terrain_manager = terrain_res.0.clone();
+            let (max_range_m, azimuth_step_deg, refraction) = (view.max_range_m, view.azimuth_step_deg, *refraction);
+            jobs.submit(radar.name.clone(), JobPriority::BACKGROUND, move |ctx| {
+                ctx.progress.set_total(1);
+                // Range step matches the 100 m viewshed grid
+                let profile = compute_horizon_profile(&radar, terrain_manager.as_ref(), max_range_m, azimuth_step_deg, 100.0, refraction);
+                ctx.progress.finish();
+                Some(profile)
+            });
         }
     }
+
+    if let Some((_, profile)) = jobs.poll().pop() {
+        view.profile = Some(profile);
+    }
+    view.computing = !jobs.is_idle();
 }
 
 fn schedule_coverage_tasks(
@@ -365,53 +886,130 @@ fn schedule_coverage_tasks(
     terrain_res: Res<TerrainResource>,
     cache: Res<CoverageCache>,
     radars: Query<(&Radar, Option<&RadarViewshed>)>,
+    jammers: Query<&Jammer>,
+    clutter: Res<ClutterModel>,
+    esm_receiver: Res<EsmReceiver>,
+    targets: Res<TargetLibrary>,
+    refraction: Res<RefractionParams>,
+    viewshed_settings: Res<ViewshedSettings>,
     mut metrics: ResMut<CoverageMetrics>,
-    // Queries to check if task already exists or chunk already loaded
-    computing: Query<&ComputingCoverage>, 
-    existing_chunks: Query<&CoverageChunk>,
+    mut jobs: ResMut<CoverageJobs>,
+    cameras: Query<(&Camera, &GlobalTransform)>,
     // Existing coverage chunks to check for stale AGL/RCS
     coverage_chunks: Query<(Entity, &CoverageChunk)>,
 ) {
     if !controller.show_coverage {
         return;
     }
+    let camera = cameras.get_single().ok();
 
-    let center_lat = 45;
-    let center_lon = 5;
-    let radius = 5; 
+    let refraction = viewshed_refraction(&refraction);
+    let k = refraction.k_factor as f32;
+    // Viewshed of a radar if computed from the current inputs
+    let current_viewshed = |radar: &Radar, viewshed: Option<&RadarViewshed>| {
+        viewshed.filter(|v| v.1 == ViewshedInputs::new(radar, k, &viewshed_settings)).map(|v| v.0.clone())
+    };
 
     // For each radar
     for (radar, viewshed_opt) in radars.iter() {
-        // If no viewshed yet, skip coverage computation for this radar
-        let viewshed = match viewshed_opt {
-            Some(v) => v.0.clone(),
-            None => continue, 
+        let radar_unique_id = radar_unique_id(&radar.name);
+
+        // The bistatic layer belongs to its transmitter: drop the other radars' tiles
+        let bistatic = controller.layer == CoverageLayer::Bistatic;
+        if bistatic && controller.bistatic_transmitter.as_ref() != Some(&radar.name) {
+            for (entity, _) in coverage_chunks.iter().filter(|(_, c)| c.radar_unique_id == radar_unique_id) {
+                commands.entity(entity).despawn_recursive();
+            }
+            jobs.cancel_where(|job| job.radar_unique_id == radar_unique_id);
+            continue;
+        }
+        // Receivers with their viewsheds, None until computed
+        let receivers: Vec<(Radar, Option<Arc<HorizonGrid>>)> = if bistatic {
+            radars.iter()
+                .filter(|(r, _)| controller.bistatic_receivers.contains(&r.name))
+                .map(|(r, v)| (r.clone(), current_viewshed(r, v)))
+                .collect()
+        } else {
+            Vec::new()
         };
 
         // Compute hash for this radar conf (including AGL and RCS)
-        let target_agl = controller.target_agl;
-        let target_rcs = controller.rcs_profile.value();
+        // The minimum altitude map does not depend on the target altitude
+        let (target_altitude, reference) = if controller.layer == CoverageLayer::MinAltitude {
+            (0.0, AltitudeReference::Agl)
+        } else {
+            (controller.target_altitude, controller.altitude_reference)
+        };
+        let Some(target) = targets.targets.get(controller.target_index) else { continue };
+        let request = CoverageRequest {
+            target: target.clone(),
+            aspect: controller.aspect,
+            target_altitude: target_altitude as f64,
+            altitude_reference: reference,
+            qnh_hpa: controller.qnh_hpa as f64,
+            step_size: 2, // Higher resolution.
+            clutter: controller.clutter_enabled.then(|| clutter.clone()),
+            jammers: if controller.jamming_enabled { jammers.iter().cloned().collect() } else { Vec::new() },
+            refraction,
+        };
         
         let mut hasher = std::collections::hash_map::DefaultHasher::new();
         use std::hash::{Hash, Hasher};
         radar.name.hash(&mut hasher);
         radar.location.latitude.to_bits().hash(&mut hasher);
         radar.location.longitude.to_bits().hash(&mut hasher);
-        radar.location.altitude.to_bits().hash(&mut hasher);
-        target_agl.to_bits().hash(&mut hasher);
-        target_rcs.to_bits().hash(&mut hasher);
+        radar.antenna_altitude_amsl().to_bits().hash(&mut hasher);
+        // Viewshed identity (geometry, k-factor, grid), so a recomputed viewshed refreshes its coverage
+        ViewshedKey::new(radar, k, &viewshed_settings, 0).digest().hash(&mut hasher);
+        target_altitude.to_bits().hash(&mut hasher);
+        reference.hash(&mut hasher);
+        if reference == AltitudeReference::FlightLevel {
+            controller.qnh_hpa.to_bits().hash(&mut hasher);
+        }
+        request.target.name.hash(&mut hasher);
+        match request.aspect {
+            AspectMode::Heading(heading) => heading.to_bits().hash(&mut hasher),
+            aspect => std::mem::discriminant(&aspect).hash(&mut hasher),
+        }
         // Add other params that affect coverage
         radar.frequency_mhz.to_bits().hash(&mut hasher);
         radar.tx_power_w.to_bits().hash(&mut hasher);
         radar.gain_dbi.to_bits().hash(&mut hasher);
+        radar.required_snr_db().to_bits().hash(&mut hasher);
+        radar.antenna.tilt_deg.to_bits().hash(&mut hasher);
+        radar.min_range_m.map(f64::to_bits).hash(&mut hasher);
+        radar.max_elevation_deg.map(f64::to_bits).hash(&mut hasher);
+        radar.instrumented_range_m.map(f64::to_bits).hash(&mut hasher);
+        radar.waveform.map(|w| (w.prf_hz.to_bits(), w.pulse_width_us.to_bits())).hash(&mut hasher);
+        radar.mti_improvement_db.to_bits().hash(&mut hasher);
+        request.clutter.as_ref().map(|c| c.sea_state).hash(&mut hasher);
+        compute_jammer_set_hash(&request.jammers).hash(&mut hasher);
+        controller.layer.hash(&mut hasher);
+        if controller.layer == CoverageLayer::Intercept {
+            esm_receiver.sensitivity_dbm.to_bits().hash(&mut hasher);
+            esm_receiver.gain_dbi.to_bits().hash(&mut hasher);
+        }
+        // An orbiting radar covers from the whole racetrack
+        if let Some(orbit) = radar_orbit(radar) {
+            orbit.center.latitude.to_bits().hash(&mut hasher);
+            orbit.center.longitude.to_bits().hash(&mut hasher);
+            orbit.heading_deg.to_bits().hash(&mut hasher);
+            orbit.length_m.to_bits().hash(&mut hasher);
+            orbit.width_m.to_bits().hash(&mut hasher);
+        }
+        for (receiver, _) in &receivers {
+            receiver.name.hash(&mut hasher);
+            receiver.location.latitude.to_bits().hash(&mut hasher);
+            receiver.location.longitude.to_bits().hash(&mut hasher);
+            receiver.antenna_altitude_amsl().to_bits().hash(&mut hasher);
+            receiver.gain_dbi.to_bits().hash(&mut hasher);
+            receiver.system_loss_db.to_bits().hash(&mut hasher);
+            receiver.required_snr_db().to_bits().hash(&mut hasher);
+            ViewshedKey::new(receiver, k, &viewshed_settings, 0).digest().hash(&mut hasher);
+        }
         
         let radar_hash = hasher.finish();
 
-        // Compute Stable ID (Name only)
-        let mut stable_hasher = std::collections::hash_map::DefaultHasher::new();
-        radar.name.hash(&mut stable_hasher);
-        let radar_unique_id = stable_hasher.finish();
-
         // 1. Check existing chunks for stale data
         for (entity, chunk) in coverage_chunks.iter() {
             // Check if this chunk belongs to THIS radar
@@ -422,79 +1020,190 @@ fn schedule_coverage_tasks(
                 }
             }
         }
+        // Same for jobs still queued or running with old parameters
+        jobs.cancel_where(|job| job.radar_unique_id == radar_unique_id && job.key.radar_hash != radar_hash);
 
-        for dlat in -radius..=radius {
-            for dlon in -radius..=radius {
-                let lat = center_lat + dlat;
-                let lon = center_lon + dlon;
+        // If no viewshed yet, or one computed from other inputs that is about to be
+        // replaced, skip coverage computation for this radar
+        let Some(viewshed) = current_viewshed(radar, viewshed_opt) else { continue };
+        // Same inputs as `viewshed`, empty unless the radar flies an orbit
+        let orbit_viewsheds = viewshed_opt.map(|v| v.2.clone()).unwrap_or_default();
+        let rx_viewsheds: Option<Vec<Arc<HorizonGrid>>> = receivers.iter().map(|(_, v)| v.clone()).collect();
+        let Some(rx_viewsheds) = rx_viewsheds else { continue };
+        if bistatic && receivers.is_empty() {
+            continue;
+        }
+        let network = MultistaticNetwork {
+            name: radar.name.clone(),
+            transmitter: radar.clone(),
+            receivers: receivers.iter().map(|(r, _)| r.clone()).collect(),
+        };
 
-                // Check overlap with ANY existing chunk for THIS radar hash
-                if existing_chunks.iter().any(|c| c.lat_idx == lat && c.lon_idx == lon && c.radar_hash == radar_hash) {
-                    continue;
-                }
-                
-                // Check if computing
-                if computing.iter().any(|c| c.lat == lat && c.lon == lon && c.radar_hash == radar_hash) {
-                    continue;
-                }
+        for (lat, lon) in coverage_area() {
+            // Check overlap with ANY existing chunk for THIS radar hash
+            if coverage_chunks.iter().any(|(_, c)| c.lat_idx == lat && c.lon_idx == lon && c.radar_hash == radar_hash) {
+                continue;
+            }
 
-                // Check Cache
-                let key = CoverageKey { 
-                    lat, 
-                    lon, 
-                    target_agl_m: target_agl as i16,
-                    radar_hash 
-                };
+            let key = CoverageKey { 
+                lat, 
+                lon, 
+                target_altitude_m: target_altitude as i16,
+                reference,
+                radar_hash 
+            };
+            let job = CoverageJob { key, radar_unique_id };
+
+            // Already queued or computing
+            if jobs.contains(&job) {
+                continue;
+            }
+            let priority = view_priority(camera, tile_center(lat, lon));
+
+            // Check Cache
+            if let Some(cached_tile) = cache.get(&key) {
+                // Spawn from Cache
+                metrics.cache_hits += 1;
+                jobs.submit(job, priority, move |ctx| {
+                    ctx.progress.set_total(1);
+                    ctx.progress.finish();
+                    Some((*cached_tile).clone())
+                });
+            } else {
+                // Trigger Computation with Viewshed
+                let terrain_manager = terrain_res.0.clone();
+                let radar_clone = radar.clone();
+                let viewshed_clone = viewshed.clone();
+                
+                let request = request.clone();
+                let layer = controller.layer;
+                let receiver = *esm_receiver;
+                let network = network.clone();
+                let rx_viewsheds = rx_viewsheds.clone();
+                let orbit_viewsheds = orbit_viewsheds.clone();
                 
-                if let Some(cached_tile) = cache.get(&key) {
-                    // Spawn from Cache
-                    metrics.cache_hits += 1;
-                    
-                    let task_pool = AsyncComputeTaskPool::get();
-                    let cached_tile_clone = cached_tile.clone();
-                    let task = task_pool.spawn(async move {
-                        (*cached_tile_clone).clone() 
+                jobs.submit(job, priority, move |ctx| {
+                    // Detection reports its rows; the other layers only their end
+                    ctx.progress.set_total(match layer {
+                        CoverageLayer::Detection => request.progress_total() * orbit_viewsheds.len().max(1) as u32,
+                        _ => 1,
                     });
-                        
-                    commands.spawn((
-                        CoverageTask(task),
-                        ComputingCoverage { lat, lon, target_agl, radar_hash, radar_unique_id }
-                    ));
-                } else {
-                    // Trigger Computation with Viewshed
-                    let task_pool = AsyncComputeTaskPool::get();
-                    let terrain_manager = terrain_res.0.clone();
-                    let radar_clone = radar.clone();
-                    let viewshed_clone = viewshed.clone();
-                    
-                    let step_size = 2; // Higher resolution.
-                    
-                    let task = task_pool.spawn(async move {
-                        let result = compute_coverage_tile(
+                    let result = match layer {
+                        CoverageLayer::Detection if !orbit_viewsheds.is_empty() => compute_orbit_coverage_tile(
+                            &radar_clone.orbit_positions(ORBIT_SAMPLES),
+                            terrain_manager,
+                            &orbit_viewsheds,
+                            lat,
+                            lon,
+                            &request,
+                            ctx,
+                        )?,
+                        CoverageLayer::Detection => compute_coverage_tile(
                             radar_clone,
                             terrain_manager, 
                             viewshed_clone,
                             lat, 
                             lon, 
-                            target_rcs, 
-                            target_agl as f64, 
-                            step_size
-                        );
-                        result
-                    });
-                    
-                    commands.spawn((
-                        CoverageTask(task),
-                        ComputingCoverage { lat, lon, target_agl, radar_hash, radar_unique_id }
-                    ));
-                }
+                            &request,
+                            ctx,
+                        )?,
+                        CoverageLayer::Intercept => compute_intercept_tile(
+                            radar_clone,
+                            terrain_manager,
+                            viewshed_clone,
+                            lat,
+                            lon,
+                            &request,
+                            &receiver,
+                        ),
+                        CoverageLayer::MinAltitude => compute_min_altitude_tile(
+                            radar_clone,
+                            terrain_manager,
+                            viewshed_clone,
+                            lat,
+                            lon,
+                            &request,
+                            &MinAltitudeSearch::default(),
+                        ),
+                        CoverageLayer::Bistatic => compute_bistatic_coverage_tile(
+                            &network,
+                            terrain_manager,
+                            viewshed_clone,
+                            &rx_viewsheds,
+                            lat,
+                            lon,
+                            &request,
+                        ),
+                    };
+                    ctx.progress.finish();
+                    Some(result)
+                });
             }
         }
     }
+
+    // The camera may have moved since the tiles were queued
+    jobs.reprioritize(|job| view_priority(camera, tile_center(job.key.lat, job.key.lon)));
+}
+fn coverage_tile_texture(tile: &radar_coverage::coverage::CoverageTile, controller: &MapController) -> Image {
+    if tile.min_altitude_amsl.is_empty() {
+        create_coverage_field_texture(tile, controller.coloring)
+    } else {
+        create_min_altitude_texture(tile, controller.min_altitude_agl, MIN_ALTITUDE_DISPLAY_MAX_M)
+    }
+}
+
+// Display-only settings re-colour the tiles already computed
+fn refresh_coverage_textures(
+    controller: Res<MapController>,
+    chunks: Query<(&CoverageChunk, &MeshMaterial3d<StandardMaterial>)>,
+    mut materials: ResMut<Assets<StandardMaterial>>,
+    mut images: ResMut<Assets<Image>>,
+    mut shown: Local<Option<(bool, CoverageColoring)>>,
+) {
+    let display = (controller.min_altitude_agl, controller.coloring);
+    if *shown == Some(display) {
+        return;
+    }
+    *shown = Some(display);
+
+    for (chunk, material) in chunks.iter() {
+        if let Some(material) = materials.get_mut(&material.0) {
+            material.base_color_texture = Some(images.add(coverage_tile_texture(&chunk.tile, &controller)));
+        }
+    }
+}
+
+// Textured quad over the 1° tile (lat, lon), at `altitude` world units
+fn coverage_quad(
+    meshes: &mut Assets<Mesh>,
+    materials: &mut Assets<StandardMaterial>,
+    texture: Handle<Image>,
+    lat: i32,
+    lon: i32,
+    altitude: f32,
+) -> (Mesh3d, MeshMaterial3d<StandardMaterial>, Transform) {
+    let center = tile_center(lat, lon);
+    let mesh_handle = meshes.add(Mesh::from(Rectangle::new(WORLD_SCALE, WORLD_SCALE)));
+    let mat_handle = materials.add(StandardMaterial {
+        base_color_texture: Some(texture),
+        alpha_mode: AlphaMode::Blend,
+        unlit: true,
+        cull_mode: None, // Double sided
+        depth_bias: 1.0, // Help with z-fighting
+        ..default()
+    });
+    (
+        Mesh3d(mesh_handle),
+        MeshMaterial3d(mat_handle),
+        Transform::from_xyz(center.x, altitude, center.z)
+            .with_rotation(Quat::from_rotation_x(-std::f32::consts::FRAC_PI_2)),
+    )
 }
+
 fn handle_coverage_tasks(
     mut commands: Commands,
-    mut tasks: Query<(Entity, &mut CoverageTask, &ComputingCoverage)>,
+    mut jobs: ResMut<CoverageJobs>,
     mut images: ResMut<Assets<Image>>,
     mut materials: ResMut<Assets<StandardMaterial>>,
     mut meshes: ResMut<Assets<Mesh>>,
@@ -508,72 +1217,108 @@ fn handle_coverage_tasks(
         for (e, _) in coverage_chunks.iter() {
            commands.entity(e).despawn_recursive();
         }
-        // Cancel tasks? 
-        for (e, _, _) in tasks.iter() {
-            commands.entity(e).despawn_recursive();
-        }
+        jobs.cancel_all();
         return;
     }
 
-    for (entity, mut task, comp) in &mut tasks {
-        if let Some(coverage_tile) = future::block_on(future::poll_once(&mut task.0)) {
-            // Task finished
-            metrics.tiles_computed += 1;
-            
-            // Insert into Cache using the radar_hash from the computing component
-            // Insert into Cache using the radar_hash and AGL from the computing component
-            let key = CoverageKey {
-                lat: coverage_tile.lat_idx,
-                lon: coverage_tile.lon_idx,
-                target_agl_m: comp.target_agl as i16,
-                radar_hash: comp.radar_hash,
-            };
-            
-            cache.insert(key, Arc::new(coverage_tile.clone()));
-
-            let image = create_coverage_texture(&coverage_tile);
-            let texture_handle = images.add(image);
-            
-            let scale = 111111.0; 
-            
-            // Coverage Mesh:
-            // Center X = lon*scale + scale/2.
-            // Center Z = -((lat+1)*scale) + scale/2.
-            
-            let center_x = (coverage_tile.lon_idx as f32) * scale + scale/2.0;
-            let center_z = -((coverage_tile.lat_idx + 1) as f32) * scale + scale/2.0;
-
-            // Prevent Z-fighting by adding a small offset based on radar hash
-            let hash_offset = (comp.radar_hash % 100) as f32 * 5.0; 
-            let altitude = 5000.0 + hash_offset;
-
-            let plane = Mesh::from(Rectangle::new(scale, scale));
-            let mesh_handle = meshes.add(plane);
-            let mat_handle = materials.add(StandardMaterial {
-                base_color_texture: Some(texture_handle),
-                alpha_mode: AlphaMode::Blend,
-                unlit: true,
-                cull_mode: None, // Double sided
-                depth_bias: 1.0, // Help with z-fighting
-                ..default()
-            });
+    for (job, coverage_tile) in jobs.poll() {
+        // Job finished
+        metrics.tiles_computed += 1;
+        let coverage_tile = Arc::new(coverage_tile);
 
-            commands.spawn((
-                Mesh3d(mesh_handle),
-                MeshMaterial3d(mat_handle),
-                Transform::from_xyz(center_x, altitude, center_z) 
-                    .with_rotation(Quat::from_rotation_x(-std::f32::consts::FRAC_PI_2)),
-                CoverageChunk { 
-                    lat_idx: coverage_tile.lat_idx, 
-                    lon_idx: coverage_tile.lon_idx,
-                    target_agl: comp.target_agl,
-                    radar_hash: comp.radar_hash,
-                    radar_unique_id: comp.radar_unique_id,
-                }
-            ));
-            
-            // Remove task
-            commands.entity(entity).despawn();
+        // Insert into Cache under the key the job was queued with
+        cache.insert(job.key, coverage_tile.clone());
+
+        let texture_handle = images.add(coverage_tile_texture(&coverage_tile, &controller));
+        // Prevent Z-fighting by adding a small offset based on radar hash
+        let altitude = COVERAGE_ALTITUDE + (job.key.radar_hash % 100) as f32 * 5.0;
+
+        commands.spawn((
+            coverage_quad(&mut meshes, &mut materials, texture_handle, coverage_tile.lat_idx, coverage_tile.lon_idx, altitude),
+            CoverageChunk { 
+                lat_idx: coverage_tile.lat_idx, 
+                lon_idx: coverage_tile.lon_idx,
+                target_altitude: job.key.target_altitude_m as f32,
+                radar_hash: job.key.radar_hash,
+                radar_unique_id: job.radar_unique_id,
+                tile: coverage_tile,
+            }
+        ));
+    }
+}
+
+// Fuse the per-radar chunks of each tile into one composite quad once every radar's
+// chunk is in. The per-radar quads stay (they track what is computed) but are hidden.
+fn update_composite_chunks(
+    mut commands: Commands,
+    controller: Res<MapController>,
+    radars: Query<&Radar>,
+    mut chunks: Query<(&CoverageChunk, &mut Visibility)>,
+    mut composites: Query<(Entity, &mut CompositeChunk, &MeshMaterial3d<StandardMaterial>)>,
+    mut images: ResMut<Assets<Image>>,
+    mut materials: ResMut<Assets<StandardMaterial>>,
+    mut meshes: ResMut<Assets<Mesh>>,
+) {
+    let mode = controller.active_composite().filter(|_| controller.show_coverage);
+    let per_radar = if mode.is_some() { Visibility::Hidden } else { Visibility::Inherited };
+    for (_, mut visibility) in chunks.iter_mut() {
+        visibility.set_if_neq(per_radar);
+    }
+    let Some(mode) = mode else {
+        for (entity, _, _) in composites.iter() {
+            commands.entity(entity).despawn_recursive();
+        }
+        return;
+    };
+
+    // Network order: radars sorted by name, as in the best radar legend
+    let mut names: Vec<&str> = radars.iter().map(|r| r.name.as_str()).collect();
+    names.sort();
+    let network: Vec<u64> = names.into_iter().map(radar_unique_id).collect();
+
+    let mut members: std::collections::HashMap<(i32, i32), Vec<Option<&CoverageChunk>>> = Default::default();
+    for (chunk, _) in chunks.iter() {
+        let Some(i) = network.iter().position(|id| *id == chunk.radar_unique_id) else { continue };
+        members.entry((chunk.lat_idx, chunk.lon_idx)).or_insert_with(|| vec![None; network.len()])[i] = Some(chunk);
+    }
+    // Complete tiles only, with the signature of the chunks they fuse
+    let mut wanted: std::collections::HashMap<(i32, i32), (u64, Vec<&CoverageChunk>)> = members
+        .into_iter()
+        .filter_map(|(tile, chunks)| {
+            let chunks: Vec<&CoverageChunk> = chunks.into_iter().collect::<Option<_>>()?;
+            use std::hash::{Hash, Hasher};
+            let mut hasher = std::collections::hash_map::DefaultHasher::new();
+            for chunk in &chunks {
+                chunk.radar_hash.hash(&mut hasher);
+            }
+            Some((tile, (hasher.finish(), chunks)))
+        })
+        .collect();
+
+    for (entity, mut composite, material) in composites.iter_mut() {
+        let tile = (composite.lat_idx, composite.lon_idx);
+        if wanted.get(&tile).map(|(signature, _)| *signature) != Some(composite.signature) {
+            commands.entity(entity).despawn_recursive();
+            continue;
         }
+        wanted.remove(&tile);
+        // A mode change only re-colours the fused tile
+        if composite.mode != mode {
+            composite.mode = mode;
+            if let Some(material) = materials.get_mut(&material.0) {
+                material.base_color_texture = Some(images.add(create_composite_texture(&composite.tile, mode)));
+            }
+        }
+    }
+
+    for ((lat, lon), (signature, chunks)) in wanted {
+        let tiles: Vec<&radar_coverage::coverage::CoverageTile> = chunks.iter().map(|c| c.tile.as_ref()).collect();
+        let Some(tile) = composite_coverage_tiles(&tiles) else { continue };
+        let texture_handle = images.add(create_composite_texture(&tile, mode));
+        let redundancy = RedundancySummary::of_tile(&tile, network.len());
+        commands.spawn((
+            coverage_quad(&mut meshes, &mut materials, texture_handle, lat, lon, COVERAGE_ALTITUDE),
+            CompositeChunk { lat_idx: lat, lon_idx: lon, signature, mode, tile, redundancy },
+        ));
     }
 }
@@ -0,0 +1,213 @@
+use serde::{Deserialize, Serialize};
+use std::io::Write;
+use std::path::Path;
+
+// Floor applied to every pattern so nulls don't produce -inf gains
+const PATTERN_FLOOR_DB: f64 = -60.0;
+
+// sinc^2(x) = 0.5 at x = 0.4429, so the half-power beamwidth maps to x = 0.8858
+const SINC_HALF_POWER_FACTOR: f64 = 0.8858;
+
+/// Tabulated one-dimensional pattern: gain relative to peak (dB) vs angle (deg).
+/// Angles must be sorted ascending; values are linearly interpolated and
+/// clamped to the end points.
+#[derive(Debug, Clone, PartialEq, Default, Serialize, Deserialize)]
+pub struct PatternTable {
+    pub angles_deg: Vec<f64>,
+    pub gains_db: Vec<f64>,
+}
+
+#[derive(Debug, Deserialize)]
+struct PatternRow {
+    angle_deg: f64,
+    gain_db: f64,
+}
+
+impl PatternTable {
+    /// Load a pattern from a CSV file with `angle_deg,gain_db` columns.
+    pub fn from_csv<P: AsRef<Path>>(path: P) -> anyhow::Result<Self> {
+        let mut reader = csv::Reader::from_path(path)?;
+        let mut rows: Vec<PatternRow> = reader.deserialize().collect::<Result<_, _>>()?;
+        if rows.is_empty() {
+            anyhow::bail!("Antenna pattern table is empty");
+        }
+        rows.sort_by(|a, b| a.angle_deg.total_cmp(&b.angle_deg));
+
+        Ok(Self {
+            angles_deg: rows.iter().map(|r| r.angle_deg).collect(),
+            gains_db: rows.iter().map(|r| r.gain_db).collect(),
+        })
+    }
+
+    /// Same `angle_deg,gain_db` layout as `from_csv`
+    pub fn write_csv<W: Write>(&self, writer: W) -> anyhow::Result<()> {
+        let mut csv = csv::Writer::from_writer(writer);
+        csv.write_record(["angle_deg", "gain_db"])?;
+        for (angle, gain) in self.angles_deg.iter().zip(&self.gains_db) {
+            csv.write_record([angle.to_string(), gain.to_string()])?;
+        }
+        csv.flush()?;
+        Ok(())
+    }
+
+    pub fn save_csv<P: AsRef<Path>>(&self, path: P) -> anyhow::Result<()> {
+        self.write_csv(std::fs::File::create(path)?)
+    }
+
+    pub fn gain_db(&self, angle_deg: f64) -> f64 {
+        interpolate(&self.angles_deg, &self.gains_db, angle_deg)
+    }
+}
+
+/// Linear interpolation in a table sorted by ascending `xs`, clamped at the ends.
+pub fn interpolate(xs: &[f64], ys: &[f64], x: f64) -> f64 {
+    let n = xs.len().min(ys.len());
+    if n == 0 {
+        return 0.0;
+    }
+    if x <= xs[0] {
+        return ys[0];
+    }
+    if x >= xs[n - 1] {
+        return ys[n - 1];
+    }
+
+    let i = xs[..n].partition_point(|&a| a <= x);
+    let (x0, x1) = (xs[i - 1], xs[i]);
+    let (y0, y1) = (ys[i - 1], ys[i]);
+    let t = (x - x0) / (x1 - x0);
+    y0 + (y1 - y0) * t
+}
+
+/// Shape of a one-dimensional pattern cut, expressed relative to boresight.
+#[derive(Debug, Clone, PartialEq, Default, Serialize, Deserialize)]
+pub enum PatternShape {
+    #[default]
+    Omni,
+    /// Uniform aperture: sinc^2 main lobe with -13 dB first sidelobes.
+    Sinc { beamwidth_deg: f64 },
+    /// Sinc main lobe below boresight, csc^2 fill above it up to `max_elevation_deg`.
+    /// Only meaningful for elevation cuts.
+    CosecantSquared { beamwidth_deg: f64, max_elevation_deg: f64 },
+    Table(PatternTable),
+}
+
+impl PatternShape {
+    /// Gain relative to peak (dB, <= 0 for parametric shapes) at `angle_deg` off boresight.
+    pub fn gain_db(&self, angle_deg: f64) -> f64 {
+        let gain = match self {
+            PatternShape::Omni => 0.0,
+            PatternShape::Sinc { beamwidth_deg } => sinc_squared_db(angle_deg, *beamwidth_deg),
+            PatternShape::CosecantSquared { beamwidth_deg, max_elevation_deg } => {
+                let edge = beamwidth_deg / 2.0;
+                if angle_deg <= edge {
+                    sinc_squared_db(angle_deg, *beamwidth_deg)
+                } else {
+                    // csc^2 power law anchored on the -3 dB point of the main lobe
+                    let fill = -3.0 + 20.0 * (edge.to_radians().sin() / angle_deg.min(90.0).to_radians().sin()).log10();
+                    if angle_deg <= *max_elevation_deg {
+                        fill
+                    } else {
+                        // Roll off beyond the shaped region like a main lobe edge
+                        let csc_edge = -3.0 + 20.0 * (edge.to_radians().sin() / max_elevation_deg.min(90.0).to_radians().sin()).log10();
+                        csc_edge + sinc_squared_db(angle_deg - max_elevation_deg, *beamwidth_deg)
+                    }
+                }
+            }
+            PatternShape::Table(table) => table.gain_db(angle_deg),
+        };
+        gain.max(PATTERN_FLOOR_DB)
+    }
+
+    /// Half-power (-3 dB) beamwidth in degrees, or None for an omni pattern.
+    /// Tables are assumed symmetric and searched outward from 0 deg.
+    pub fn beamwidth_deg(&self) -> Option<f64> {
+        match self {
+            PatternShape::Omni => None,
+            PatternShape::Sinc { beamwidth_deg } | PatternShape::CosecantSquared { beamwidth_deg, .. } => Some(*beamwidth_deg),
+            PatternShape::Table(table) => table
+                .angles_deg
+                .iter()
+                .zip(&table.gains_db)
+                .find(|(angle, gain)| **angle > 0.0 && **gain <= -3.0)
+                .map(|(angle, _)| 2.0 * angle),
+        }
+    }
+}
+
+fn sinc_squared_db(angle_deg: f64, beamwidth_deg: f64) -> f64 {
+    if beamwidth_deg <= 0.0 {
+        return 0.0;
+    }
+    let x = std::f64::consts::PI * SINC_HALF_POWER_FACTOR * angle_deg / beamwidth_deg;
+    if x.abs() < 1e-9 {
+        return 0.0;
+    }
+    let sinc = x.sin() / x;
+    20.0 * sinc.abs().max(1e-6).log10()
+}
+
+/// Antenna radiation pattern as separable azimuth and elevation cuts.
+#[derive(Debug, Clone, PartialEq, Default, Serialize, Deserialize)]
+pub struct AntennaPattern {
+    #[serde(default)]
+    pub azimuth: PatternShape,
+    #[serde(default)]
+    pub elevation: PatternShape,
+    /// Mechanical tilt of the elevation boresight (degrees, positive up)
+    #[serde(default)]
+    pub tilt_deg: f64,
+    /// Fixed pointing direction. None means a rotating antenna that sweeps its
+    /// main beam across every azimuth.
+    #[serde(default)]
+    pub boresight_azimuth_deg: Option<f64>,
+}
+
+impl AntennaPattern {
+    /// Gain relative to peak (dB) toward a direction given in true azimuth and elevation.
+    pub fn relative_gain_db(&self, azimuth_deg: f64, elevation_deg: f64) -> f64 {
+        let az_off = match self.boresight_azimuth_deg {
+            Some(boresight) => wrap_deg(azimuth_deg - boresight),
+            None => 0.0,
+        };
+        self.relative_gain_off_boresight_db(az_off, elevation_deg)
+    }
+
+    /// Gain relative to peak (dB) toward (azimuth, elevation) while a rotating beam
+    /// is steered at `steer_azimuth_deg`. Fixed antennas ignore the steering.
+    pub fn relative_gain_steered_db(&self, steer_azimuth_deg: f64, azimuth_deg: f64, elevation_deg: f64) -> f64 {
+        match self.boresight_azimuth_deg {
+            Some(_) => self.relative_gain_db(azimuth_deg, elevation_deg),
+            None => self.relative_gain_off_boresight_db(wrap_deg(azimuth_deg - steer_azimuth_deg), elevation_deg),
+        }
+    }
+
+    /// Gain relative to peak (dB) for an azimuth offset from the beam axis.
+    /// Used when the beam is steered at one direction and we look at another
+    /// (e.g. sidelobe reception of a jammer).
+    pub fn relative_gain_off_boresight_db(&self, azimuth_offset_deg: f64, elevation_deg: f64) -> f64 {
+        let el_off = elevation_deg - self.tilt_deg;
+        self.azimuth.gain_db(azimuth_offset_deg.abs()) + self.elevation.gain_db(el_off)
+    }
+}
+
+/// Wrap an angle to [-180, 180) degrees.
+pub fn wrap_deg(angle_deg: f64) -> f64 {
+    (angle_deg + 180.0).rem_euclid(360.0) - 180.0
+}
+
+/// True if `azimuth_deg` lies inside the (min, max) sector, handling wrap-around
+/// through North for azimuth sectors such as (300, 60).
+pub fn in_azimuth_sector(azimuth_deg: f64, sector: (f64, f64)) -> bool {
+    if (sector.1 - sector.0).abs() >= 360.0 {
+        return true;
+    }
+    let az = azimuth_deg.rem_euclid(360.0);
+    let min = sector.0.rem_euclid(360.0);
+    let max = sector.1.rem_euclid(360.0);
+    if min <= max {
+        az >= min && az <= max
+    } else {
+        az >= min || az <= max
+    }
+}
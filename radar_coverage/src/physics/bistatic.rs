@@ -0,0 +1,142 @@
+use serde::{Deserialize, Serialize};
+use std::f64::consts::PI;
+use crate::geo::LatLon;
+use crate::io::Radar;
+use crate::physics::los::calculate_geodesic;
+use crate::physics::radar_eq::{calculate_noise_power_w, calculate_wavelength};
+
+/// One transmitter paired with several receiver sites.
+/// Receivers are described as `Radar` so they reuse location, antenna gain and
+/// pattern, losses and detection settings; their transmit parameters are ignored.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct MultistaticNetwork {
+    pub name: String,
+    pub transmitter: Radar,
+    pub receivers: Vec<Radar>,
+}
+
+// Each site carries half of its `system_loss_db` (transmit or receive side) so a
+// co-located transmitter/receiver pair reduces to the monostatic equation.
+fn bistatic_loss_lin(tx: &Radar, rx: &Radar) -> f64 {
+    10.0f64.powf((tx.system_loss_db + rx.system_loss_db) / 2.0 / 10.0)
+}
+
+/// Bistatic received power (W): Pr = Pt*Gt*Gr*lambda^2*sigma_b / ((4pi)^3 * Rt^2 * Rr^2 * L)
+pub fn calculate_bistatic_received_power(
+    tx: &Radar,
+    rx: &Radar,
+    tx_dist_m: f64,
+    rx_dist_m: f64,
+    rcs_sqm: f64,
+    tx_gain_dbi: f64,
+    rx_gain_dbi: f64,
+) -> f64 {
+    if tx_dist_m <= 0.0 || rx_dist_m <= 0.0 { return f64::INFINITY; }
+
+    let wavelength = calculate_wavelength(tx.frequency_mhz);
+    let gt = 10.0f64.powf(tx_gain_dbi / 10.0);
+    let gr = 10.0f64.powf(rx_gain_dbi / 10.0);
+
+    let numerator = tx.tx_power_w * gt * gr * wavelength.powi(2) * rcs_sqm;
+    let denominator = (4.0 * PI).powi(3) * tx_dist_m.powi(2) * rx_dist_m.powi(2) * bistatic_loss_lin(tx, rx);
+
+    numerator / denominator
+}
+
+pub fn calculate_bistatic_snr_db(
+    tx: &Radar,
+    rx: &Radar,
+    tx_dist_m: f64,
+    rx_dist_m: f64,
+    rcs_sqm: f64,
+    tx_gain_dbi: f64,
+    rx_gain_dbi: f64,
+) -> f64 {
+    let pr = calculate_bistatic_received_power(tx, rx, tx_dist_m, rx_dist_m, rcs_sqm, tx_gain_dbi, rx_gain_dbi);
+    let noise = calculate_noise_power_w(None, None);
+
+    10.0 * (pr / noise).log10()
+}
+
+/// Maximum range product (Rt * Rr)_max in m² for peak gains, using the
+/// receiver's required SNR. Detection contours are Cassini ovals Rt * Rr = const.
+pub fn bistatic_range_product(tx: &Radar, rx: &Radar, rcs_sqm: f64) -> f64 {
+    let snr_min_lin = 10.0f64.powf(rx.required_snr_db() / 10.0);
+    let noise = calculate_noise_power_w(None, None);
+    let pr_at_unit_product = calculate_bistatic_received_power(tx, rx, 1.0, 1.0, rcs_sqm, tx.gain_dbi, rx.gain_dbi);
+
+    (pr_at_unit_product / (noise * snr_min_lin)).sqrt()
+}
+
+/// Cassini oval Rt * Rr = `range_product` around a transmitter/receiver baseline.
+/// Returns one closed contour when the oval encloses both sites, or two (one
+/// around each site) when the baseline is too long for a single oval.
+/// Uses a local flat-earth projection, adequate for baselines of a few hundred km.
+pub fn cassini_oval(tx: LatLon, rx: LatLon, range_product: f64, n_points: usize) -> Vec<Vec<LatLon>> {
+    let (baseline, bearing_deg) = calculate_geodesic(tx, rx);
+    let a = baseline / 2.0;
+    let b2 = range_product; // b^2 with b^4 = (Rt * Rr)^2
+    let mid = LatLon {
+        latitude: (tx.latitude + rx.latitude) / 2.0,
+        longitude: (tx.longitude + rx.longitude) / 2.0,
+        altitude: 0.0,
+    };
+    let beta = bearing_deg.to_radians();
+
+    // Local (along-baseline, across-baseline) metres to geographic coordinates
+    let to_latlon = |u: f64, v: f64| {
+        let east = u * beta.sin() - v * beta.cos();
+        let north = u * beta.cos() + v * beta.sin();
+        LatLon {
+            latitude: mid.latitude + north / 111111.0,
+            longitude: mid.longitude + east / (111111.0 * mid.latitude.to_radians().cos()),
+            altitude: 0.0,
+        }
+    };
+
+    let n = n_points.max(8);
+    // Polar form about the midpoint: r^4 - 2 a^2 r^2 cos(2t) + a^4 = b^4
+    let radius_sq = |theta: f64, sign: f64| {
+        let disc = b2 * b2 - a.powi(4) * (2.0 * theta).sin().powi(2);
+        if disc < 0.0 {
+            return None;
+        }
+        let r2 = a * a * (2.0 * theta).cos() + sign * disc.sqrt();
+        (r2 >= 0.0).then_some(r2)
+    };
+
+    if b2 >= a * a {
+        let contour = (0..=n)
+            .filter_map(|i| {
+                let theta = 2.0 * PI * i as f64 / n as f64;
+                radius_sq(theta, 1.0).map(|r2| to_latlon(r2.sqrt() * theta.cos(), r2.sqrt() * theta.sin()))
+            })
+            .collect();
+        return vec![contour];
+    }
+
+    // Two ovals: sweep the half-angle where the oval exists, outer then inner branch
+    let theta_max = 0.5 * (b2 / (a * a)).asin();
+    [0.0, PI]
+        .iter()
+        .map(|&center| {
+            let mut outer = Vec::with_capacity(n + 1);
+            let mut inner = Vec::with_capacity(n + 1);
+            for i in 0..=n {
+                let theta = -theta_max + 2.0 * theta_max * i as f64 / n as f64;
+                if let Some(r2) = radius_sq(theta, 1.0) {
+                    outer.push(to_latlon(r2.sqrt() * (center + theta).cos(), r2.sqrt() * (center + theta).sin()));
+                }
+                if let Some(r2) = radius_sq(theta, -1.0) {
+                    inner.push(to_latlon(r2.sqrt() * (center + theta).cos(), r2.sqrt() * (center + theta).sin()));
+                }
+            }
+            inner.reverse();
+            outer.extend(inner);
+            if let Some(first) = outer.first().copied() {
+                outer.push(first);
+            }
+            outer
+        })
+        .collect()
+}
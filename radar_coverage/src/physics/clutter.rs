@@ -0,0 +1,112 @@
+use bevy::prelude::*;
+use serde::{Deserialize, Serialize};
+use crate::physics::antenna::interpolate;
+
+// Grazing angles below this are treated as zero (no backscatter under constant gamma)
+const MIN_GRAZING_RAD: f64 = 1e-5;
+
+/// Coarse terrain classes used to pick a clutter reflectivity.
+/// Without a land-cover dataset the class is inferred from the ground elevation.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum TerrainClass {
+    Sea,
+    Flatland,
+    Hills,
+    Mountains,
+}
+
+impl TerrainClass {
+    pub fn from_altitude(altitude_m: f64) -> Self {
+        if altitude_m <= 0.0 {
+            TerrainClass::Sea
+        } else if altitude_m < 300.0 {
+            TerrainClass::Flatland
+        } else if altitude_m < 1000.0 {
+            TerrainClass::Hills
+        } else {
+            TerrainClass::Mountains
+        }
+    }
+}
+
+/// Normalised backscatter sigma-zero (m²/m²) as a function of grazing angle.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub enum SigmaZeroModel {
+    /// sigma0 = gamma * sin(grazing)
+    ConstantGamma { gamma_db: f64 },
+    /// Tabulated sigma0 (dB) vs grazing angle (deg), linearly interpolated
+    Table { grazing_deg: Vec<f64>, sigma0_db: Vec<f64> },
+}
+
+impl SigmaZeroModel {
+    pub fn sigma0_db(&self, grazing_rad: f64) -> f64 {
+        let grazing = grazing_rad.max(MIN_GRAZING_RAD);
+        match self {
+            SigmaZeroModel::ConstantGamma { gamma_db } => gamma_db + 10.0 * grazing.sin().log10(),
+            SigmaZeroModel::Table { grazing_deg, sigma0_db } => interpolate(grazing_deg, sigma0_db, grazing.to_degrees()),
+        }
+    }
+}
+
+/// Land and sea clutter environment shared by all radars.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, Resource)]
+pub struct ClutterModel {
+    pub flatland: SigmaZeroModel,
+    pub hills: SigmaZeroModel,
+    pub mountains: SigmaZeroModel,
+    /// Douglas sea state (0-6) used for water cells
+    pub sea_state: u8,
+}
+
+impl Default for ClutterModel {
+    fn default() -> Self {
+        // Typical constant-gamma values for S-band (Nathanson / Billingsley)
+        Self {
+            flatland: SigmaZeroModel::ConstantGamma { gamma_db: -18.0 },
+            hills: SigmaZeroModel::ConstantGamma { gamma_db: -12.0 },
+            mountains: SigmaZeroModel::ConstantGamma { gamma_db: -6.0 },
+            sea_state: 3,
+        }
+    }
+}
+
+impl ClutterModel {
+    /// sigma0 (dB) for a terrain class at the given grazing angle
+    pub fn sigma0_db(&self, class: TerrainClass, grazing_rad: f64) -> f64 {
+        match class {
+            TerrainClass::Sea => sea_sigma0_db(self.sea_state, grazing_rad),
+            TerrainClass::Flatland => self.flatland.sigma0_db(grazing_rad),
+            TerrainClass::Hills => self.hills.sigma0_db(grazing_rad),
+            TerrainClass::Mountains => self.mountains.sigma0_db(grazing_rad),
+        }
+    }
+
+    /// Clutter RCS (m²) of the resolution cell at `range_m`.
+    /// Uses the pulse-limited cell area R * theta_az * (c tau / 2) * sec(grazing).
+    pub fn clutter_rcs_sqm(
+        &self,
+        class: TerrainClass,
+        grazing_rad: f64,
+        range_m: f64,
+        azimuth_beamwidth_deg: f64,
+        range_resolution_m: f64,
+    ) -> f64 {
+        let grazing = grazing_rad.max(MIN_GRAZING_RAD);
+        let area = range_m * azimuth_beamwidth_deg.to_radians() * range_resolution_m / grazing.cos();
+        let sigma0 = 10.0f64.powf(self.sigma0_db(class, grazing) / 10.0);
+        sigma0 * area
+    }
+}
+
+/// Sea clutter sigma0 (dB): constant gamma rising ~5 dB per sea state,
+/// from -40 dB (glassy) to -10 dB (very rough, sea state 6).
+pub fn sea_sigma0_db(sea_state: u8, grazing_rad: f64) -> f64 {
+    let gamma_db = -40.0 + 5.0 * sea_state.min(6) as f64;
+    gamma_db + 10.0 * grazing_rad.max(MIN_GRAZING_RAD).sin().log10()
+}
+
+/// Grazing angle (rad) at a ground cell seen under `ground_elevation_rad` from
+/// the radar, accounting for the effective-earth curvature between the two.
+pub fn grazing_angle_rad(ground_elevation_rad: f64, dist_m: f64, effective_radius_m: f64) -> f64 {
+    (-ground_elevation_rad - dist_m / effective_radius_m).max(0.0)
+}
@@ -0,0 +1,57 @@
+use bevy::prelude::*;
+use serde::{Deserialize, Serialize};
+use std::f64::consts::PI;
+use crate::io::Radar;
+use crate::physics::radar_eq::calculate_wavelength;
+
+/// Passive ESM receiver used to evaluate where a radar's emissions can be intercepted.
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize, Resource)]
+pub struct EsmReceiver {
+    pub sensitivity_dbm: f64, // Minimum detectable signal at the antenna port
+    pub gain_dbi: f64,        // Receiver antenna gain toward the emitter
+    pub system_loss_db: f64,  // Cabling, polarisation and processing losses
+}
+
+impl Default for EsmReceiver {
+    fn default() -> Self {
+        // Typical wide-open airborne RWR/ESM front end
+        Self {
+            sensitivity_dbm: -65.0,
+            gain_dbi: 0.0,
+            system_loss_db: 3.0,
+        }
+    }
+}
+
+fn watts_to_dbm(power_w: f64) -> f64 {
+    10.0 * (power_w * 1000.0).log10()
+}
+
+/// One-way received power (dBm) at the ESM receiver, using the radar ERP toward
+/// the receiver. None if the direction is outside the radar's transmit sectors.
+pub fn intercept_power_dbm(radar: &Radar, receiver: &EsmReceiver, dist_m: f64, azimuth_deg: f64, elevation_deg: f64) -> Option<f64> {
+    if dist_m <= 0.0 {
+        return Some(f64::INFINITY);
+    }
+    let gain_dbi = radar.gain_towards_dbi(azimuth_deg, elevation_deg)?;
+    let erp_w = radar.get_erps_w() * 10.0f64.powf((gain_dbi - radar.gain_dbi) / 10.0);
+
+    let wavelength = calculate_wavelength(radar.frequency_mhz);
+    let g_rx = 10.0f64.powf(receiver.gain_dbi / 10.0);
+    let l_rx = 10.0f64.powf(receiver.system_loss_db / 10.0);
+
+    // One-way link: Pr = ERP * Gr * lambda^2 / ((4pi)^2 * R^2 * L)
+    let pr = erp_w * g_rx * wavelength.powi(2) / ((4.0 * PI).powi(2) * dist_m.powi(2) * l_rx);
+    Some(watts_to_dbm(pr))
+}
+
+/// Free-space intercept range (m) in the radar's main beam.
+pub fn intercept_range(radar: &Radar, receiver: &EsmReceiver) -> f64 {
+    let wavelength = calculate_wavelength(radar.frequency_mhz);
+    let g_rx = 10.0f64.powf(receiver.gain_dbi / 10.0);
+    let l_rx = 10.0f64.powf(receiver.system_loss_db / 10.0);
+    let sensitivity_w = 10.0f64.powf(receiver.sensitivity_dbm / 10.0) / 1000.0;
+
+    // R = sqrt( ERP * Gr * lambda^2 / ((4pi)^2 * L * S_min) )
+    (radar.get_erps_w() * g_rx * wavelength.powi(2) / ((4.0 * PI).powi(2) * l_rx * sensitivity_w)).sqrt()
+}
@@ -0,0 +1,113 @@
+use std::io::Write;
+use std::path::Path;
+use serde::{Deserialize, Serialize};
+use crate::geo::LatLon;
+use crate::io::Radar;
+use crate::physics::los::{destination_point, TerrainProvider};
+use crate::physics::refraction::{effective_earth_radius, RefractionParams};
+
+/// Terrain masking angle along one azimuth
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct HorizonSample {
+    pub azimuth_deg: f64,
+    pub elevation_deg: f64, // Apparent elevation of the masking terrain (effective-earth model)
+    pub distance_m: f64,    // Distance to the terrain point that sets the horizon
+}
+
+/// Panoramic horizon of a radar site: masking elevation angle vs azimuth,
+/// as compared by site surveyors with measured horizons.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct HorizonProfile {
+    pub radar_name: String,
+    pub origin: LatLon,
+    pub antenna_altitude_m: f64, // AMSL
+    pub k_factor: f64,
+    pub azimuth_step_deg: f64,
+    pub samples: Vec<HorizonSample>,
+}
+
+impl HorizonProfile {
+    /// Masking elevation (deg) at any azimuth, linearly interpolated between samples
+    pub fn elevation_at(&self, azimuth_deg: f64) -> Option<f64> {
+        let n = self.samples.len();
+        if n == 0 {
+            return None;
+        }
+        let pos = azimuth_deg.rem_euclid(360.0) / self.azimuth_step_deg;
+        let i0 = pos.floor() as usize % n;
+        let i1 = (i0 + 1) % n;
+        let t = pos - pos.floor();
+        Some(self.samples[i0].elevation_deg * (1.0 - t) + self.samples[i1].elevation_deg * t)
+    }
+
+    /// azimuth_deg,elevation_deg,distance_m
+    pub fn write_csv<W: Write>(&self, writer: W) -> anyhow::Result<()> {
+        let mut csv = csv::Writer::from_writer(writer);
+        csv.write_record(["azimuth_deg", "elevation_deg", "distance_m"])?;
+        for sample in &self.samples {
+            csv.write_record([
+                format!("{:.2}", sample.azimuth_deg),
+                format!("{:.4}", sample.elevation_deg),
+                format!("{:.0}", sample.distance_m),
+            ])?;
+        }
+        csv.flush()?;
+        Ok(())
+    }
+
+    pub fn save_csv<P: AsRef<Path>>(&self, path: P) -> anyhow::Result<()> {
+        self.write_csv(std::fs::File::create(path)?)
+    }
+
+    pub fn save_json<P: AsRef<Path>>(&self, path: P) -> anyhow::Result<()> {
+        let writer = std::io::BufWriter::new(std::fs::File::create(path)?);
+        serde_json::to_writer_pretty(writer, self)?;
+        Ok(())
+    }
+}
+
+/// March outward along each azimuth and keep the running maximum elevation
+/// angle of the terrain, as `compute_viewshed` does along its rays.
+pub fn compute_horizon_profile<T: TerrainProvider>(
+    radar: &Radar,
+    terrain: &T,
+    max_range_m: f64,
+    azimuth_step_deg: f64,
+    range_step_m: f64,
+    refraction: RefractionParams,
+) -> HorizonProfile {
+    // Whole number of azimuths around the circle
+    let n_azimuths = (360.0 / azimuth_step_deg.clamp(0.01, 360.0)).round().max(1.0) as usize;
+    let azimuth_step = 360.0 / n_azimuths as f64;
+    let range_step = range_step_m.max(1.0);
+    let n_ranges = (max_range_m / range_step).floor() as usize;
+
+    let two_k_r = 2.0 * effective_earth_radius(refraction);
+    let h_radar = radar.antenna_altitude_amsl();
+
+    let samples = (0..n_azimuths)
+        .map(|i| {
+            let azimuth_deg = i as f64 * azimuth_step;
+            let mut best = HorizonSample { azimuth_deg, elevation_deg: -90.0, distance_m: 0.0 };
+            for j in 1..=n_ranges {
+                let dist = j as f64 * range_step;
+                let ground = terrain.get_altitude(destination_point(radar.location, azimuth_deg, dist));
+                let elevation_deg = ((ground - h_radar - dist * dist / two_k_r) / dist).atan().to_degrees();
+                if elevation_deg > best.elevation_deg {
+                    best.elevation_deg = elevation_deg;
+                    best.distance_m = dist;
+                }
+            }
+            best
+        })
+        .collect();
+
+    HorizonProfile {
+        radar_name: radar.name.clone(),
+        origin: radar.location,
+        antenna_altitude_m: h_radar,
+        k_factor: refraction.k_factor,
+        azimuth_step_deg: azimuth_step,
+        samples,
+    }
+}
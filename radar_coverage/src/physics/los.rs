@@ -43,7 +43,7 @@ impl LosSystem {
         let step_size_m = 100.0; // 100m steps for MVP (should be adaptive)
         let steps = (dist_m / step_size_m).ceil() as usize;
 
-        let h_radar = radar.location.altitude + radar.antenna_height_agl;
+        let h_radar = radar.antenna_altitude_amsl();
         
         // Pre-calculate target effective parameters for final check
         // h_tgt_eff = h_tgt_amsl - d^2 / (2 * R_eff)
@@ -127,3 +127,21 @@ pub fn calculate_geodesic(p1: LatLon, p2: LatLon) -> (f64, f64) {
 
     (dist, bearing)
 }
+
+/// Spherical direct problem: point at `dist_m` from `origin` along `bearing_deg`.
+/// Inverse of `calculate_geodesic`; the altitude is copied from `origin`.
+pub fn destination_point(origin: LatLon, bearing_deg: f64, dist_m: f64) -> LatLon {
+    let lat1 = origin.latitude.to_radians();
+    let lon1 = origin.longitude.to_radians();
+    let bearing = bearing_deg.to_radians();
+    let delta = dist_m / EARTH_RADIUS;
+
+    let lat2 = (lat1.sin() * delta.cos() + lat1.cos() * delta.sin() * bearing.cos()).asin();
+    let lon2 = lon1 + (bearing.sin() * delta.sin() * lat1.cos()).atan2(delta.cos() - lat1.sin() * lat2.sin());
+
+    LatLon {
+        latitude: lat2.to_degrees(),
+        longitude: lon2.to_degrees(),
+        altitude: origin.altitude,
+    }
+}
@@ -2,3 +2,12 @@ pub mod los;
 pub mod refraction;
 pub mod radar_eq;
 pub mod viewshed;
+pub mod polar_viewshed;
+pub mod detection;
+pub mod antenna;
+pub mod pulse;
+pub mod clutter;
+pub mod esm;
+pub mod bistatic;
+pub mod target;
+pub mod horizon;
@@ -0,0 +1,132 @@
+use std::sync::atomic::Ordering;
+use rayon::prelude::*;
+use crate::geo::{LatLon, EARTH_RADIUS};
+use crate::io::Radar;
+use crate::physics::los::{calculate_geodesic, destination_point, TerrainProvider};
+use crate::physics::viewshed::ViewshedMonitor;
+
+// Terrain is sampled at least this finely along each azimuth (SRTM3 spacing), so
+// ridges between two range bins still mask the bins behind them.
+const TERRAIN_SAMPLE_M: f64 = 90.0;
+
+/// Horizon angles on a range / azimuth grid centred on the radar.
+/// Memory grows with range bins × azimuth bins instead of with the square of the range.
+#[derive(Debug, Clone, PartialEq)]
+pub struct PolarViewshed {
+    pub origin: LatLon,
+    pub range_bin_m: f64,
+    pub azimuth_bin_deg: f64,
+    pub n_ranges: usize,   // Bin 0 is the radar itself
+    pub n_azimuths: usize, // Azimuth i is i * azimuth_bin_deg from true north
+    /// Masking angle (radians) per bin, one row of `n_ranges` per azimuth.
+    /// Same meaning as `Viewshed::horizon_map`.
+    pub horizon_map: Vec<f32>,
+}
+
+impl PolarViewshed {
+    pub fn new(origin: LatLon, radius_m: f64, range_bin_m: f64, azimuth_bin_deg: f64) -> Self {
+        let range_bin_m = range_bin_m.max(1.0);
+        // Whole number of azimuths around the circle
+        let n_azimuths = (360.0 / azimuth_bin_deg.clamp(0.01, 360.0)).round().max(1.0) as usize;
+        let n_ranges = (radius_m / range_bin_m).floor() as usize + 1;
+        Self {
+            origin,
+            range_bin_m,
+            azimuth_bin_deg: 360.0 / n_azimuths as f64,
+            n_ranges,
+            n_azimuths,
+            horizon_map: vec![-std::f32::consts::FRAC_PI_2; n_ranges * n_azimuths],
+        }
+    }
+
+    pub fn radius_m(&self) -> f64 {
+        (self.n_ranges - 1) as f64 * self.range_bin_m
+    }
+
+    pub fn bin(&self, range_idx: usize, azimuth_idx: usize) -> f32 {
+        self.horizon_map[azimuth_idx * self.n_ranges + range_idx]
+    }
+
+    /// Nearest bin to a range / bearing, None beyond the last range bin
+    pub fn nearest_bin(&self, range_m: f64, azimuth_deg: f64) -> Option<(usize, usize)> {
+        if !(0.0..=self.radius_m()).contains(&range_m) {
+            return None;
+        }
+        let range_idx = (range_m / self.range_bin_m).round() as usize;
+        let azimuth_idx = (azimuth_deg.rem_euclid(360.0) / self.azimuth_bin_deg).round() as usize % self.n_azimuths;
+        Some((range_idx.min(self.n_ranges - 1), azimuth_idx))
+    }
+
+    /// Masking angle (radians) bilinearly interpolated in range and azimuth
+    pub fn horizon_angle_at(&self, range_m: f64, azimuth_deg: f64) -> Option<f32> {
+        if !(0.0..=self.radius_m()).contains(&range_m) {
+            return None;
+        }
+        let pr = range_m / self.range_bin_m;
+        let r0 = (pr.floor() as usize).min(self.n_ranges - 1);
+        let r1 = (r0 + 1).min(self.n_ranges - 1);
+        let tr = (pr - r0 as f64).clamp(0.0, 1.0) as f32;
+        // Bin 0 is the radar (-90°): inside the first bin use the first real bin
+        let (r0, tr) = if r0 == 0 { (r1, 0.0) } else { (r0, tr) };
+
+        let pa = azimuth_deg.rem_euclid(360.0) / self.azimuth_bin_deg;
+        let a0 = pa.floor() as usize % self.n_azimuths;
+        let a1 = (a0 + 1) % self.n_azimuths;
+        let ta = (pa - pa.floor()) as f32;
+
+        let along = |a: usize| self.bin(r0, a) * (1.0 - tr) + self.bin(r1, a) * tr;
+        Some(along(a0) * (1.0 - ta) + along(a1) * ta)
+    }
+
+    /// Equivalent of `Viewshed::get_horizon_angle`
+    pub fn get_horizon_angle(&self, loc: LatLon) -> Option<f32> {
+        let (dist, bearing) = calculate_geodesic(self.origin, loc);
+        self.horizon_angle_at(dist, bearing)
+    }
+
+    pub fn memory_bytes(&self) -> usize {
+        self.horizon_map.len() * std::mem::size_of::<f32>()
+    }
+}
+
+/// March outward along every azimuth bin and keep the running maximum terrain
+/// angle, recorded at each range bin. Azimuths run in parallel; progress counts
+/// one unit per azimuth. Once `monitor.cancel` is raised the remaining azimuths are skipped.
+pub fn compute_polar_viewshed<T: TerrainProvider + Sync + ?Sized>(
+    radar: &Radar,
+    terrain: &T,
+    max_range_m: f64,
+    range_bin_m: f64,
+    azimuth_bin_deg: f64,
+    k_factor: f64,
+    monitor: ViewshedMonitor,
+) -> PolarViewshed {
+    let ViewshedMonitor { progress, cancel } = monitor;
+    let mut viewshed = PolarViewshed::new(radar.location, max_range_m, range_bin_m, azimuth_bin_deg);
+    let (n_ranges, range_bin, azimuth_bin) = (viewshed.n_ranges, viewshed.range_bin_m, viewshed.azimuth_bin_deg);
+    let substeps = (range_bin / TERRAIN_SAMPLE_M).ceil().max(1.0) as usize;
+    let step = range_bin / substeps as f64;
+
+    let two_k_r = 2.0 * k_factor * EARTH_RADIUS;
+    let h_radar = radar.antenna_altitude_amsl();
+
+    viewshed.horizon_map.par_chunks_mut(n_ranges).enumerate().for_each(|(i, row)| {
+        if cancel.is_some_and(|c| c.is_cancelled()) {
+            return;
+        }
+        let azimuth_deg = i as f64 * azimuth_bin;
+        let mut max_angle = -std::f64::consts::FRAC_PI_2;
+        for s in 1..(n_ranges - 1) * substeps + 1 {
+            let dist = s as f64 * step;
+            let ground = terrain.get_altitude(destination_point(radar.location, azimuth_deg, dist));
+            max_angle = max_angle.max(((ground - h_radar - dist * dist / two_k_r) / dist).atan());
+            if s % substeps == 0 {
+                row[s / substeps] = max_angle as f32;
+            }
+        }
+        if let Some(p) = &progress {
+            p.fetch_add(1, Ordering::Relaxed);
+        }
+    });
+    viewshed
+}
@@ -0,0 +1,92 @@
+use serde::{Deserialize, Serialize};
+
+const C_LIGHT: f64 = 299_792_458.0;
+
+// Eclipsing below this received fraction is treated as a total loss of the echo
+const MIN_ECLIPSE_FRACTION: f64 = 1e-3;
+
+/// Pulsed waveform timing. The receiver is blanked while transmitting, so echoes
+/// arriving during a transmit pulse are eclipsed (partially or totally).
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct PulseWaveform {
+    pub prf_hz: f64,
+    pub pulse_width_us: f64,
+}
+
+impl PulseWaveform {
+    pub fn pri_s(&self) -> f64 {
+        1.0 / self.prf_hz
+    }
+
+    pub fn pulse_width_s(&self) -> f64 {
+        self.pulse_width_us * 1e-6
+    }
+
+    pub fn duty_cycle(&self) -> f64 {
+        (self.pulse_width_s() * self.prf_hz).min(1.0)
+    }
+
+    /// Maximum unambiguous range c / (2 PRF)
+    pub fn unambiguous_range_m(&self) -> f64 {
+        C_LIGHT / (2.0 * self.prf_hz)
+    }
+
+    /// Range extent of one pulse c * tau / 2
+    pub fn pulse_range_extent_m(&self) -> f64 {
+        C_LIGHT * self.pulse_width_s() / 2.0
+    }
+
+    /// Range at which a target at `range_m` appears once folded into the first PRI
+    pub fn apparent_range_m(&self, range_m: f64) -> f64 {
+        range_m.rem_euclid(self.unambiguous_range_m())
+    }
+
+    pub fn is_range_ambiguous(&self, range_m: f64) -> bool {
+        range_m > self.unambiguous_range_m()
+    }
+
+    /// Fraction (0..1) of the echo energy that falls outside the transmit blanking.
+    pub fn eclipsing_fraction(&self, range_m: f64) -> f64 {
+        let pri = self.pri_s();
+        let tau = self.pulse_width_s();
+        if tau <= 0.0 {
+            return 1.0;
+        }
+        if tau >= pri {
+            return 0.0; // CW-like duty cycle, receiver never open
+        }
+
+        // Echo occupies [t, t + tau] within the PRI; the receiver is blind on
+        // [0, tau] and again on [pri, pri + tau] for the next transmission.
+        let t = (2.0 * range_m / C_LIGHT).rem_euclid(pri);
+        let overlap_start = (tau - t).max(0.0);
+        let overlap_end = (t + tau - pri).max(0.0);
+        let eclipsed = (overlap_start + overlap_end).min(tau);
+
+        1.0 - eclipsed / tau
+    }
+
+    /// SNR loss (dB, >= 0) due to eclipsing. The matched filter output amplitude
+    /// scales with the unblanked fraction, so SNR scales with its square.
+    pub fn eclipsing_loss_db(&self, range_m: f64) -> f64 {
+        let fraction = self.eclipsing_fraction(range_m).max(MIN_ECLIPSE_FRACTION);
+        -20.0 * fraction.log10()
+    }
+
+    /// Range bands (start, end) in metres, up to `max_range_m`, where echoes
+    /// overlap a transmit pulse and are at least partially eclipsed.
+    pub fn blind_range_bands(&self, max_range_m: f64) -> Vec<(f64, f64)> {
+        let ru = self.unambiguous_range_m();
+        let extent = self.pulse_range_extent_m();
+        let mut bands = Vec::new();
+
+        let mut n = 0.0;
+        while n * ru - extent < max_range_m {
+            let start = (n * ru - extent).max(0.0);
+            let end = (n * ru + extent).min(max_range_m);
+            bands.push((start, end));
+            n += 1.0;
+        }
+        bands
+    }
+}
@@ -1,5 +1,5 @@
 use std::f64::consts::PI;
-use crate::io::Radar;
+use crate::io::{Radar, Jammer, JammingType};
 
 const C_LIGHT: f64 = 299_792_458.0;
 const BOLTZMANN: f64 = 1.380649e-23;
@@ -14,6 +14,11 @@ pub fn calculate_wavelength(freq_mhz: f64) -> f64 {
     C_LIGHT / (freq_mhz * 1e6)
 }
 
+/// Range resolution c / (2B) of the default receiver bandwidth
+pub fn default_range_resolution_m() -> f64 {
+    C_LIGHT / (2.0 * DEFAULT_BANDWIDTH_HZ)
+}
+
 pub fn calculate_noise_power_w(bandwidth_hz: Option<f64>, noise_figure_db: Option<f64>) -> f64 {
     let b = bandwidth_hz.unwrap_or(DEFAULT_BANDWIDTH_HZ);
     let nf = noise_figure_db.unwrap_or(DEFAULT_NOISE_FIGURE_DB);
@@ -22,10 +27,15 @@ pub fn calculate_noise_power_w(bandwidth_hz: Option<f64>, noise_figure_db: Optio
 }
 
 pub fn calculate_received_power(radar: &Radar, dist_m: f64, rcs_sqm: f64) -> f64 {
+    calculate_received_power_with_gain(radar, dist_m, rcs_sqm, radar.gain_dbi)
+}
+
+/// Monostatic received power using the antenna gain toward the target instead of the peak gain.
+pub fn calculate_received_power_with_gain(radar: &Radar, dist_m: f64, rcs_sqm: f64, gain_dbi: f64) -> f64 {
     if dist_m <= 0.0 { return f64::INFINITY; }
     
     let wavelength = calculate_wavelength(radar.frequency_mhz);
-    let g_lin = 10.0f64.powf(radar.gain_dbi / 10.0);
+    let g_lin = 10.0f64.powf(gain_dbi / 10.0);
     let l_sys_lin = 10.0f64.powf(radar.system_loss_db / 10.0);
     
     // Monostatic radar equation: Pr = (Pt * G^2 * lambda^2 * sigma) / ((4pi)^3 * R^4 * L)
@@ -42,11 +52,25 @@ pub fn calculate_snr_db(radar: &Radar, dist_m: f64, rcs_sqm: f64) -> f64 {
     10.0 * (pr / noise).log10()
 }
 
+/// SNR (dB) for a target seen in a given direction, or None if the direction is blanked.
+pub fn calculate_snr_db_towards(radar: &Radar, dist_m: f64, rcs_sqm: f64, azimuth_deg: f64, elevation_deg: f64) -> Option<f64> {
+    let gain = radar.gain_towards_dbi(azimuth_deg, elevation_deg)?;
+    Some(calculate_snr_db_with_gain(radar, dist_m, rcs_sqm, gain))
+}
+
+/// SNR (dB) using an explicit antenna gain (also used for clutter-to-noise with the clutter RCS).
+pub fn calculate_snr_db_with_gain(radar: &Radar, dist_m: f64, rcs_sqm: f64, gain_dbi: f64) -> f64 {
+    let pr = calculate_received_power_with_gain(radar, dist_m, rcs_sqm, gain_dbi);
+    let noise = calculate_noise_power_w(None, None);
+
+    10.0 * (pr / noise).log10()
+}
+
 pub fn max_detection_range(radar: &Radar, rcs_sqm: f64) -> f64 {
     let wavelength = calculate_wavelength(radar.frequency_mhz);
     let g_lin = 10.0f64.powf(radar.gain_dbi / 10.0);
     let l_sys_lin = 10.0f64.powf(radar.system_loss_db / 10.0);
-    let snr_min_lin = 10.0f64.powf(radar.snr_threshold_db / 10.0);
+    let snr_min_lin = 10.0f64.powf(radar.required_snr_db() / 10.0);
     let noise = calculate_noise_power_w(None, None);
     
     // R = [ (Pt * G^2 * lambda^2 * sigma) / ((4pi)^3 * L * N * SNR_min) ] ^ (1/4)
@@ -55,3 +79,86 @@ pub fn max_detection_range(radar: &Radar, rcs_sqm: f64) -> f64 {
     
     (numerator / denominator).powf(0.25)
 }
+
+/// Fraction of the jammer power that falls inside the radar receiver bandwidth.
+pub fn jammer_bandwidth_factor(radar: &Radar, jammer: &Jammer) -> f64 {
+    if jammer.bandwidth_hz <= 0.0 {
+        return 0.0;
+    }
+    let in_band = (DEFAULT_BANDWIDTH_HZ / jammer.bandwidth_hz).min(1.0);
+    match jammer.jamming_type {
+        // A spot jammer is assumed to be tuned on the radar
+        JammingType::Spot => in_band,
+        JammingType::NoiseBarrage => {
+            let half_band_mhz = jammer.bandwidth_hz / 2.0 / 1e6;
+            if (radar.frequency_mhz - jammer.center_frequency_mhz).abs() <= half_band_mhz {
+                in_band
+            } else {
+                0.0
+            }
+        }
+    }
+}
+
+/// One-way jamming power (W) at the radar receiver output.
+/// `jammer_erp_w` is the ERP toward the radar, `radar_gain_dbi` the radar
+/// antenna gain toward the jammer (main beam or sidelobe).
+pub fn calculate_jamming_power_w(radar: &Radar, jammer: &Jammer, jammer_erp_w: f64, dist_m: f64, radar_gain_dbi: f64) -> f64 {
+    if dist_m <= 0.0 { return f64::INFINITY; }
+
+    let wavelength = calculate_wavelength(radar.frequency_mhz);
+    let g_lin = 10.0f64.powf(radar_gain_dbi / 10.0);
+    let l_sys_lin = 10.0f64.powf(radar.system_loss_db / 10.0);
+
+    // J = ERP * Gr * lambda^2 * Fb / ((4pi)^2 * Rj^2 * L)
+    let numerator = jammer_erp_w * g_lin * wavelength.powi(2) * jammer_bandwidth_factor(radar, jammer);
+    let denominator = (4.0 * PI).powi(2) * dist_m.powi(2) * l_sys_lin;
+
+    numerator / denominator
+}
+
+/// Jamming-to-noise ratio (dB) for a jammer received with the given geometry.
+pub fn calculate_jnr_db(radar: &Radar, jammer: &Jammer, jammer_erp_w: f64, dist_m: f64, radar_gain_dbi: f64) -> f64 {
+    let j = calculate_jamming_power_w(radar, jammer, jammer_erp_w, dist_m, radar_gain_dbi);
+    let noise = calculate_noise_power_w(None, None);
+    10.0 * (j / noise).log10()
+}
+
+/// Jamming-to-signal ratio (dB) for a target at `target_dist_m` and a jammer at `jammer_dist_m`.
+/// For a self-protection jammer both distances are equal and both gains are the main-beam gain.
+pub fn calculate_jsr_db(
+    radar: &Radar,
+    jammer: &Jammer,
+    target_dist_m: f64,
+    rcs_sqm: f64,
+    target_gain_dbi: f64,
+    jammer_dist_m: f64,
+    jammer_gain_dbi: f64,
+) -> f64 {
+    let s = calculate_received_power_with_gain(radar, target_dist_m, rcs_sqm, target_gain_dbi);
+    let j = calculate_jamming_power_w(radar, jammer, jammer.erp_w, jammer_dist_m, jammer_gain_dbi);
+    10.0 * (j / s).log10()
+}
+
+/// Burn-through range (m) against a self-protection jammer: the range below which
+/// S / (J + N) reaches the radar's required SNR. Signal falls as R^-4 and
+/// jamming as R^-2, so this solves q*N*R^4 + q*b*R^2 - a = 0 for R^2.
+pub fn self_protection_burn_through_range(radar: &Radar, jammer: &Jammer, rcs_sqm: f64) -> f64 {
+    let q = 10.0f64.powf(radar.required_snr_db() / 10.0);
+    let noise = calculate_noise_power_w(None, None);
+    // S = a / R^4 and J = b / R^2
+    let a = calculate_received_power(radar, 1.0, rcs_sqm);
+    let b = calculate_jamming_power_w(radar, jammer, jammer.erp_w, 1.0, radar.gain_dbi);
+
+    let r_sq = (-q * b + ((q * b).powi(2) + 4.0 * q * noise * a).sqrt()) / (2.0 * q * noise);
+    r_sq.sqrt()
+}
+
+/// Detection range (m) in the presence of a constant jamming power (stand-off jammer)
+pub fn stand_off_burn_through_range(radar: &Radar, rcs_sqm: f64, jamming_power_w: f64) -> f64 {
+    let q = 10.0f64.powf(radar.required_snr_db() / 10.0);
+    let noise = calculate_noise_power_w(None, None);
+    let a = calculate_received_power(radar, 1.0, rcs_sqm);
+
+    (a / (q * (noise + jamming_power_w))).powf(0.25)
+}
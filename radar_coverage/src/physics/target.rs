@@ -0,0 +1,296 @@
+use bevy::prelude::*;
+use serde::{Deserialize, Serialize};
+use crate::physics::antenna::{interpolate, wrap_deg};
+
+/// IEEE radar frequency bands
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum FrequencyBand {
+    HF,
+    VHF,
+    UHF,
+    L,
+    S,
+    C,
+    X,
+    Ku,
+    K,
+    Ka,
+}
+
+impl FrequencyBand {
+    pub const ALL: [FrequencyBand; 10] = [
+        FrequencyBand::HF,
+        FrequencyBand::VHF,
+        FrequencyBand::UHF,
+        FrequencyBand::L,
+        FrequencyBand::S,
+        FrequencyBand::C,
+        FrequencyBand::X,
+        FrequencyBand::Ku,
+        FrequencyBand::K,
+        FrequencyBand::Ka,
+    ];
+
+    /// (min, max) frequency in MHz
+    pub fn range_mhz(self) -> (f64, f64) {
+        match self {
+            FrequencyBand::HF => (3.0, 30.0),
+            FrequencyBand::VHF => (30.0, 300.0),
+            FrequencyBand::UHF => (300.0, 1000.0),
+            FrequencyBand::L => (1000.0, 2000.0),
+            FrequencyBand::S => (2000.0, 4000.0),
+            FrequencyBand::C => (4000.0, 8000.0),
+            FrequencyBand::X => (8000.0, 12000.0),
+            FrequencyBand::Ku => (12000.0, 18000.0),
+            FrequencyBand::K => (18000.0, 27000.0),
+            FrequencyBand::Ka => (27000.0, 40000.0),
+        }
+    }
+
+    /// Band containing `frequency_mhz`, clamped to HF / Ka outside the table
+    pub fn from_mhz(frequency_mhz: f64) -> Self {
+        FrequencyBand::ALL
+            .into_iter()
+            .find(|band| frequency_mhz < band.range_mhz().1)
+            .unwrap_or(FrequencyBand::Ka)
+    }
+
+    // Geometric centre, used to pick the nearest tabulated band
+    fn center_mhz(self) -> f64 {
+        let (min, max) = self.range_mhz();
+        (min * max).sqrt()
+    }
+}
+
+/// RCS (dBsm) tabulated against aspect angle, bilinearly interpolated in dB.
+/// Aspect azimuth is measured from the target nose (0 = head-on, 180 = tail),
+/// aspect elevation is positive when the radar is above the target.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct RcsTable {
+    pub azimuth_deg: Vec<f64>,
+    pub elevation_deg: Vec<f64>,
+    pub rcs_dbsm: Vec<Vec<f64>>, // One row per elevation, one column per azimuth
+    /// Left/right symmetric target: azimuths only cover 0..180
+    #[serde(default = "default_symmetric")]
+    pub symmetric: bool,
+}
+
+fn default_symmetric() -> bool {
+    true
+}
+
+impl RcsTable {
+    /// Aspect-independent RCS
+    pub fn constant(rcs_dbsm: f64) -> Self {
+        Self {
+            azimuth_deg: vec![0.0],
+            elevation_deg: vec![0.0],
+            rcs_dbsm: vec![vec![rcs_dbsm]],
+            symmetric: true,
+        }
+    }
+
+    pub fn rcs_dbsm(&self, aspect_azimuth_deg: f64, aspect_elevation_deg: f64) -> f64 {
+        let az = if self.symmetric {
+            wrap_deg(aspect_azimuth_deg).abs()
+        } else {
+            aspect_azimuth_deg.rem_euclid(360.0)
+        };
+        let row_values: Vec<f64> = self.rcs_dbsm.iter()
+            .map(|row| interpolate(&self.azimuth_deg, row, az))
+            .collect();
+        interpolate(&self.elevation_deg, &row_values, aspect_elevation_deg)
+    }
+
+    pub fn max_dbsm(&self) -> f64 {
+        self.rcs_dbsm.iter().flatten().copied().fold(f64::NEG_INFINITY, f64::max)
+    }
+
+    // Per elevation row: smallest RCS over all aspects, and the power average
+    // over the azimuth circle (trapezoidal on the tabulated knots).
+    fn reduce_azimuth(&self, mode: AspectMode) -> Vec<f64> {
+        self.rcs_dbsm.iter()
+            .map(|row| match mode {
+                AspectMode::WorstCase => row.iter().copied().fold(f64::INFINITY, f64::min),
+                _ => {
+                    let n = self.azimuth_deg.len().min(row.len());
+                    if n < 2 {
+                        return row.first().copied().unwrap_or(0.0);
+                    }
+                    let lin = |i: usize| 10.0f64.powf(row[i] / 10.0);
+                    let mut area = 0.0;
+                    for i in 1..n {
+                        area += 0.5 * (lin(i - 1) + lin(i)) * (self.azimuth_deg[i] - self.azimuth_deg[i - 1]);
+                    }
+                    let span = self.azimuth_deg[n - 1] - self.azimuth_deg[0];
+                    10.0 * (area / span).log10()
+                }
+            })
+            .collect()
+    }
+}
+
+/// RCS table valid for one frequency band
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct BandRcs {
+    pub band: FrequencyBand,
+    pub table: RcsTable,
+}
+
+/// How the target aspect toward each radar is chosen
+#[derive(Debug, Clone, Copy, PartialEq, Default, Serialize, Deserialize)]
+pub enum AspectMode {
+    /// Target flying on a fixed true heading (deg)
+    Heading(f64),
+    /// Smallest RCS over all azimuth aspects
+    WorstCase,
+    /// Power average over all azimuth aspects
+    #[default]
+    Average,
+}
+
+impl AspectMode {
+    pub fn label(&self) -> &'static str {
+        match self {
+            AspectMode::Heading(_) => "Fixed heading",
+            AspectMode::WorstCase => "Worst case",
+            AspectMode::Average => "Aspect average",
+        }
+    }
+}
+
+/// Target model: aspect-dependent RCS tables per frequency band.
+/// Loaded from JSON files (see `io::load_target_library`) or `builtin_targets`.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct TargetModel {
+    pub name: String,
+    pub bands: Vec<BandRcs>,
+}
+
+impl TargetModel {
+    /// Single aspect- and frequency-independent RCS (m²)
+    pub fn isotropic(name: &str, rcs_sqm: f64) -> Self {
+        Self {
+            name: name.to_string(),
+            bands: vec![BandRcs { band: FrequencyBand::S, table: RcsTable::constant(10.0 * rcs_sqm.log10()) }],
+        }
+    }
+
+    /// Table for the radar frequency: the matching band, else the nearest tabulated one
+    pub fn table_for(&self, frequency_mhz: f64) -> Option<&RcsTable> {
+        let band = FrequencyBand::from_mhz(frequency_mhz);
+        self.bands.iter()
+            .find(|b| b.band == band)
+            .or_else(|| {
+                self.bands.iter().min_by(|a, b| {
+                    let da = (a.band.center_mhz() / frequency_mhz).ln().abs();
+                    let db = (b.band.center_mhz() / frequency_mhz).ln().abs();
+                    da.total_cmp(&db)
+                })
+            })
+            .map(|b| &b.table)
+    }
+
+    /// Nose-on RCS (m²) in the horizontal plane, e.g. for an inbound self-protection jammer
+    pub fn nose_rcs_sqm(&self, frequency_mhz: f64) -> f64 {
+        self.table_for(frequency_mhz).map_or(1.0, |t| 10.0f64.powf(t.rcs_dbsm(0.0, 0.0) / 10.0))
+    }
+
+    /// Resolve the model for one radar frequency and aspect mode
+    pub fn signature(&self, frequency_mhz: f64, aspect: AspectMode) -> TargetSignature {
+        let table = self.table_for(frequency_mhz).cloned().unwrap_or_else(|| RcsTable::constant(0.0));
+        match aspect {
+            AspectMode::Heading(heading_deg) => TargetSignature::Heading { table, heading_deg },
+            mode => TargetSignature::Reduced {
+                rcs_dbsm: table.reduce_azimuth(mode),
+                elevation_deg: table.elevation_deg,
+            },
+        }
+    }
+}
+
+/// A target model resolved for one radar, evaluated per coverage cell
+#[derive(Debug, Clone)]
+pub enum TargetSignature {
+    Heading { table: RcsTable, heading_deg: f64 },
+    Reduced { elevation_deg: Vec<f64>, rcs_dbsm: Vec<f64> },
+}
+
+impl TargetSignature {
+    /// RCS (m²) of a target seen by a radar on `bearing_deg` (radar to target)
+    /// under `elevation_deg` (radar to target).
+    pub fn rcs_sqm(&self, bearing_deg: f64, elevation_deg: f64) -> f64 {
+        let dbsm = match self {
+            TargetSignature::Heading { table, heading_deg } => {
+                // Direction from the target back to the radar, relative to its nose
+                table.rcs_dbsm(bearing_deg + 180.0 - heading_deg, -elevation_deg)
+            }
+            TargetSignature::Reduced { elevation_deg: els, rcs_dbsm } => interpolate(els, rcs_dbsm, -elevation_deg),
+        };
+        10.0f64.powf(dbsm / 10.0)
+    }
+
+    /// Upper bound of `rcs_sqm`, used for the maximum range prefilter
+    pub fn max_rcs_sqm(&self) -> f64 {
+        let dbsm = match self {
+            TargetSignature::Heading { table, .. } => table.max_dbsm(),
+            TargetSignature::Reduced { rcs_dbsm, .. } => rcs_dbsm.iter().copied().fold(f64::NEG_INFINITY, f64::max),
+        };
+        10.0f64.powf(dbsm / 10.0)
+    }
+}
+
+// Nose, 30, 60, beam, 120, 150, tail
+const AZIMUTH_KNOTS: [f64; 7] = [0.0, 30.0, 60.0, 90.0, 120.0, 150.0, 180.0];
+
+fn aircraft_table(azimuth_dbsm: [f64; 7], look_up_down_db: f64) -> RcsTable {
+    // Seen from above or below, the planform adds to the side-on RCS
+    let row = |offset: f64| azimuth_dbsm.iter().map(|v| v + offset).collect();
+    RcsTable {
+        azimuth_deg: AZIMUTH_KNOTS.to_vec(),
+        elevation_deg: vec![-30.0, 0.0, 30.0],
+        rcs_dbsm: vec![row(look_up_down_db), row(0.0), row(look_up_down_db)],
+        symmetric: true,
+    }
+}
+
+/// Built-in library, replacing the former fixed scalar RCS profiles
+pub fn builtin_targets() -> Vec<TargetModel> {
+    vec![
+        TargetModel {
+            name: "5G Stealth Fighter".to_string(),
+            bands: vec![
+                // Shaping is tuned for X/S band, far less effective at VHF
+                BandRcs { band: FrequencyBand::X, table: aircraft_table([-20.0, -17.0, -15.0, -5.0, -15.0, -17.0, -10.0], 5.0) },
+                BandRcs { band: FrequencyBand::S, table: aircraft_table([-15.0, -13.0, -11.0, 0.0, -11.0, -13.0, -8.0], 5.0) },
+                BandRcs { band: FrequencyBand::VHF, table: aircraft_table([0.0, 1.0, 2.0, 8.0, 2.0, 1.0, 3.0], 3.0) },
+            ],
+        },
+        TargetModel {
+            name: "4G Fighter".to_string(),
+            bands: vec![
+                BandRcs { band: FrequencyBand::S, table: aircraft_table([3.0, 5.0, 7.0, 15.0, 7.0, 5.0, 8.0], 3.0) },
+            ],
+        },
+        TargetModel::isotropic("Small Aircraft", 2.0),
+        TargetModel {
+            name: "Large Aircraft".to_string(),
+            bands: vec![
+                BandRcs { band: FrequencyBand::S, table: aircraft_table([12.0, 14.0, 17.0, 25.0, 17.0, 14.0, 15.0], 3.0) },
+            ],
+        },
+        TargetModel::isotropic("Ship", 5000.0),
+    ]
+}
+
+/// Target models available in the UI: built-ins plus user files
+#[derive(Debug, Clone, Resource)]
+pub struct TargetLibrary {
+    pub targets: Vec<TargetModel>,
+}
+
+impl Default for TargetLibrary {
+    fn default() -> Self {
+        Self { targets: builtin_targets() }
+    }
+}
@@ -1,10 +1,10 @@
 use crate::geo::LatLon;
-use crate::terrain::TerrainManager;
 use crate::io::Radar;
 use std::collections::HashMap;
 use std::sync::Arc;
 
 /// Represents a dense grid of visibility data relative to a radar
+#[derive(Debug, Clone, PartialEq)]
 pub struct Viewshed {
     pub origin: LatLon,
     pub radius_m: f64,
@@ -57,6 +57,18 @@ impl Viewshed {
         Some((x as usize, y as usize))
     }
 
+    /// Point whose horizon is stored in cell (x, y): the cell's south-west corner,
+    /// so that the radar cell samples the radar position itself.
+    pub fn grid_to_latlon(&self, x: usize, y: usize) -> LatLon {
+        let dx_m = (x as f64 - (self.width / 2) as f64) * self.cell_size_m;
+        let dy_m = (y as f64 - (self.height / 2) as f64) * self.cell_size_m;
+        LatLon {
+            latitude: self.origin.latitude + dy_m / 111111.0,
+            longitude: self.origin.longitude + dx_m / (111111.0 * self.origin.latitude.to_radians().cos()),
+            altitude: 0.0,
+        }
+    }
+
     pub fn get_horizon_angle(&self, loc: LatLon) -> Option<f32> {
         if let Some((x, y)) = self.latlon_to_grid(loc) {
             return Some(self.horizon_map[y * self.width + x]);
@@ -65,35 +77,251 @@ impl Viewshed {
     }
 }
 
-use bevy::prelude::Component;
+use bevy::prelude::Resource;
+use serde::{Deserialize, Serialize};
 
-#[derive(Component)]
-pub struct ViewshedProgress {
-    pub current: Arc<AtomicU32>,
-    pub total: u32,
-}
 
 
 
 use crate::physics::refraction::RefractionParams;
 use crate::geo::EARTH_RADIUS;
 use crate::physics::los::TerrainProvider;
+use crate::physics::polar_viewshed::{compute_polar_viewshed, PolarViewshed};
+use crate::jobs::CancelToken;
 
 use std::sync::atomic::{AtomicU32, Ordering};
+use rayon::prelude::*;
+
+/// Largest Cartesian grid R3 is run on (2000 x 2000 cells, 200 km square at 100 m).
+/// Its cost grows with the cube of the grid side, so larger grids fall back to XDraw.
+pub const R3_MAX_CELLS: usize = 4_000_000;
+
+/// How the horizon grid is filled
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default, Serialize, Deserialize)]
+pub enum ViewshedAlgorithm {
+    /// Bresenham rays to the perimeter (legacy): aliasing far out, last-write-wins near the radar
+    #[default]
+    RayCast,
+    /// Exact line of sight to every cell with interpolated terrain crossings. O(n³), reference quality
+    R3,
+    /// Ring-by-ring propagation from the two inner neighbours straddling the line of sight. O(n²)
+    XDraw,
+}
+
+impl ViewshedAlgorithm {
+    pub const ALL: [ViewshedAlgorithm; 3] = [ViewshedAlgorithm::RayCast, ViewshedAlgorithm::R3, ViewshedAlgorithm::XDraw];
+
+    pub fn label(&self) -> &'static str {
+        match self {
+            ViewshedAlgorithm::RayCast => "Ray casting (default)",
+            ViewshedAlgorithm::R3 => "R3 (reference, slow)",
+            ViewshedAlgorithm::XDraw => "XDraw (fast)",
+        }
+    }
+
+    /// Whether the algorithm can fill a Cartesian grid of `range_m` radius at `cell_size_m`
+    /// within its budget (only R3 has one)
+    pub fn fits(&self, range_m: f64, cell_size_m: f64) -> bool {
+        let side = (range_m * 2.0 / cell_size_m).ceil() as usize;
+        *self != ViewshedAlgorithm::R3 || side.saturating_mul(side) <= R3_MAX_CELLS
+    }
+
+    /// Units reported through the progress counter for a grid of `width` x `height`:
+    /// rays for ray casting, rows for R3, rings for XDraw.
+    pub fn progress_total(&self, width: usize, height: usize) -> u32 {
+        match self {
+            ViewshedAlgorithm::RayCast => (2 * (width + height)) as u32,
+            ViewshedAlgorithm::R3 => height as u32,
+            ViewshedAlgorithm::XDraw => (width.max(height) / 2) as u32,
+        }
+    }
+}
+
+/// Progress counter and cancellation token of one viewshed computation, both optional
+#[derive(Debug, Clone, Default)]
+pub struct ViewshedMonitor<'a> {
+    pub progress: Option<Arc<AtomicU32>>,
+    pub cancel: Option<&'a CancelToken>,
+}
+
+/// Viewshed engine settings shared by all radars
+#[derive(Debug, Clone, Copy, PartialEq, Resource)]
+pub struct ViewshedSettings {
+    pub algorithm: ViewshedAlgorithm, // Cartesian grid only
+    pub grid: ViewshedGrid,
+    pub range_m: f64,
+    pub cell_size_m: f64,
+}
+
+impl Default for ViewshedSettings {
+    fn default() -> Self {
+        Self {
+            algorithm: ViewshedAlgorithm::default(),
+            grid: ViewshedGrid::default(),
+            range_m: 470_000.0,
+            cell_size_m: 100.0,
+        }
+    }
+}
+
+impl ViewshedSettings {
+    /// Progress units of `compute_horizon_grid` with these settings
+    pub fn progress_total(&self) -> u32 {
+        match self.grid {
+            ViewshedGrid::Cartesian => {
+                let width = (self.range_m * 2.0 / self.cell_size_m).ceil() as usize;
+                self.algorithm.progress_total(width, width)
+            }
+            ViewshedGrid::Polar { azimuth_bin_deg, .. } => (360.0 / azimuth_bin_deg.clamp(0.01, 360.0)).round().max(1.0) as u32,
+        }
+    }
+}
+
+/// Layout of the horizon grid
+#[derive(Debug, Clone, Copy, PartialEq, Default, Serialize, Deserialize)]
+pub enum ViewshedGrid {
+    /// Square grid of `cell_size_m` cells
+    #[default]
+    Cartesian,
+    /// Range / azimuth bins around the radar
+    Polar { range_bin_m: f64, azimuth_bin_deg: f64 },
+}
+
+impl ViewshedGrid {
+    pub fn label(&self) -> &'static str {
+        match self {
+            ViewshedGrid::Cartesian => "Cartesian",
+            ViewshedGrid::Polar { .. } => "Polar (range/azimuth)",
+        }
+    }
+}
+
+/// Horizon of one radar on either grid, as consumed by the coverage computations
+#[derive(Debug, Clone, PartialEq)]
+pub enum HorizonGrid {
+    Cartesian(Viewshed),
+    Polar(PolarViewshed),
+}
+
+impl From<Viewshed> for HorizonGrid {
+    fn from(viewshed: Viewshed) -> Self {
+        HorizonGrid::Cartesian(viewshed)
+    }
+}
+
+impl From<PolarViewshed> for HorizonGrid {
+    fn from(viewshed: PolarViewshed) -> Self {
+        HorizonGrid::Polar(viewshed)
+    }
+}
+
+impl HorizonGrid {
+    /// Masking angle (radians) at `loc`, None outside the grid
+    pub fn get_horizon_angle(&self, loc: LatLon) -> Option<f32> {
+        match self {
+            HorizonGrid::Cartesian(viewshed) => viewshed.get_horizon_angle(loc),
+            HorizonGrid::Polar(viewshed) => viewshed.get_horizon_angle(loc),
+        }
+    }
+
+    pub fn memory_bytes(&self) -> usize {
+        match self {
+            HorizonGrid::Cartesian(viewshed) => viewshed.horizon_map.len() * std::mem::size_of::<f32>(),
+            HorizonGrid::Polar(viewshed) => viewshed.memory_bytes(),
+        }
+    }
+}
+
+/// Viewshed on the grid selected in `settings`
+pub fn compute_horizon_grid<T: TerrainProvider + Sync + ?Sized>(
+    radar: &Radar,
+    terrain: &T,
+    settings: &ViewshedSettings,
+    k_factor: f32,
+    progress: Option<Arc<AtomicU32>>,
+    cancel: Option<&CancelToken>,
+) -> HorizonGrid {
+    let monitor = ViewshedMonitor { progress, cancel };
+    match settings.grid {
+        ViewshedGrid::Cartesian => compute_cartesian(
+            radar, terrain, settings.range_m, settings.cell_size_m, k_factor, settings.algorithm, monitor,
+        ).into(),
+        ViewshedGrid::Polar { range_bin_m, azimuth_bin_deg } => compute_polar_viewshed(
+            radar, terrain, settings.range_m, range_bin_m, azimuth_bin_deg, k_factor as f64, monitor,
+        ).into(),
+    }
+}
 
 // Optimized Viewshed Computation
-pub fn compute_viewshed(
-    radar: &Radar, 
-    terrain: &TerrainManager, 
-    max_range_m: f64, 
+pub fn compute_viewshed<T: TerrainProvider + Sync + ?Sized>(
+    radar: &Radar,
+    terrain: &T,
+    max_range_m: f64,
     k_factor: f32,
     progress: Option<Arc<AtomicU32>>
 ) -> Viewshed {
-    let cell_size = 100.0; // 100m resolution
-    let mut viewshed = Viewshed::new(radar.location, max_range_m, cell_size);
+    compute_viewshed_with_algorithm(radar, terrain, max_range_m, 100.0, k_factor, ViewshedAlgorithm::RayCast, progress)
+}
+
+pub fn compute_viewshed_with_algorithm<T: TerrainProvider + Sync + ?Sized>(
+    radar: &Radar,
+    terrain: &T,
+    max_range_m: f64,
+    cell_size_m: f64,
+    k_factor: f32,
+    algorithm: ViewshedAlgorithm,
+    progress: Option<Arc<AtomicU32>>
+) -> Viewshed {
+    compute_cartesian(radar, terrain, max_range_m, cell_size_m, k_factor, algorithm, ViewshedMonitor { progress, cancel: None })
+}
+
+// A cancelled computation stops early and returns a partial grid, to be discarded
+fn compute_cartesian<T: TerrainProvider + Sync + ?Sized>(
+    radar: &Radar,
+    terrain: &T,
+    max_range_m: f64,
+    cell_size_m: f64,
+    k_factor: f32,
+    algorithm: ViewshedAlgorithm,
+    monitor: ViewshedMonitor,
+) -> Viewshed {
+    let ViewshedMonitor { progress, cancel } = monitor;
+    let mut viewshed = Viewshed::new(radar.location, max_range_m, cell_size_m);
+    let algorithm = if algorithm.fits(max_range_m, cell_size_m) {
+        algorithm
+    } else {
+        eprintln!(
+            "R3 viewshed of {}: {}x{} cells exceed the budget of {}, using XDraw",
+            radar.name, viewshed.width, viewshed.height, R3_MAX_CELLS,
+        );
+        ViewshedAlgorithm::XDraw
+    };
+    match algorithm {
+        ViewshedAlgorithm::RayCast => compute_ray_cast(&mut viewshed, radar, terrain, max_range_m, k_factor, progress, cancel),
+        ViewshedAlgorithm::R3 => compute_r3(&mut viewshed, radar, terrain, max_range_m, k_factor as f64, progress, cancel),
+        ViewshedAlgorithm::XDraw => compute_xdraw(&mut viewshed, radar, terrain, max_range_m, k_factor as f64, progress, cancel),
+    }
+    viewshed
+}
+
+fn is_cancelled(cancel: Option<&CancelToken>) -> bool {
+    cancel.is_some_and(|c| c.is_cancelled())
+}
+
+fn compute_ray_cast<T: TerrainProvider + Sync + ?Sized>(
+    viewshed: &mut Viewshed,
+    radar: &Radar,
+    terrain: &T,
+    max_range_m: f64,
+    k_factor: f32,
+    progress: Option<Arc<AtomicU32>>,
+    cancel: Option<&CancelToken>,
+) {
+    let cell_size = viewshed.cell_size_m;
     
     let center_x = viewshed.width as isize / 2;
     let center_y = viewshed.height as isize / 2;
+    let h_radar = radar.antenna_altitude_amsl(); // Platform-dependent (mast, aircraft altitude)
     // let radius_cells = (max_range_m / cell_size).ceil() as isize;
     
     // We compute 8 octants or sweep 360 degrees. 
@@ -105,8 +333,16 @@ pub fn compute_viewshed(
     let min_y = 0;
     let max_y = viewshed.height as isize - 1;
 
+    let (width, height) = (viewshed.width as isize, viewshed.height as isize);
+
+    // Rays overlap near the radar. They run in parallel and merge with an atomic
+    // max, so the result does not depend on the order in which rays finish.
+    let horizon: Vec<AtomicU32> = (0..viewshed.horizon_map.len())
+        .map(|_| AtomicU32::new(encode_angle(-std::f32::consts::FRAC_PI_2)))
+        .collect();
+
     // Ray casting function
-    let cast_ray = |end_x: isize, end_y: isize, viewshed: &mut Viewshed| {
+    let cast_ray = |end_x: isize, end_y: isize, horizon: &[AtomicU32]| {
         let mut x = center_x;
         let mut y = center_y;
         
@@ -121,8 +357,8 @@ pub fn compute_viewshed(
         
         loop {
             // Process current cell (x, y)
-             if x >= 0 && x < viewshed.width as isize && y >= 0 && y < viewshed.height as isize {
-                let idx = (y as usize) * viewshed.width + (x as usize);
+             if x >= 0 && x < width && y >= 0 && y < height {
+                let idx = (y as usize) * width as usize + (x as usize);
                 
                 // Get ground altitude at this cell
                 // Convert grid (x,y) back to lat/lon? Or direct query if we knew bounds?
@@ -161,22 +397,17 @@ pub fn compute_viewshed(
                     // Wait, we want the horizon angle *imposed* by this terrain point.
                     // The angle TO this ground point is:
                     
-                    let height_diff = h_ground as f64 - radar.location.altitude - curvature_drop;
+                    let height_diff = h_ground as f64 - h_radar - curvature_drop;
                     let angle = (height_diff / dist).atan() as f32;
                     
                     if angle > max_angle {
-                        max_angle = angle;
                         // This point forms a new horizon
-                        viewshed.horizon_map[idx] = max_angle;
-                    } else {
-                        // This point is below previous horizon, so the horizon remains the same
-                        // (Ideally we store the *masking* angle, which is max_angle)
-                        viewshed.horizon_map[idx] = max_angle;
+                        max_angle = angle;
                     }
-                } else if dist == 0.0 {
-                    // At radar
-                     viewshed.horizon_map[idx] = -std::f32::consts::FRAC_PI_2;
+                    // Store the *masking* angle; where rays overlap the highest one wins
+                    horizon[idx].fetch_max(encode_angle(max_angle), Ordering::Relaxed);
                 }
+                // At radar (dist == 0) the horizon stays at -90 deg
             }
 
             if x == end_x && y == end_y { break; }
@@ -186,31 +417,283 @@ pub fn compute_viewshed(
         }
     };
     
-    // Perimeter Traversal
-    // Top and Bottom
-    let mut ray_count = 0;
-    
-    for x in min_x..=max_x {
-        cast_ray(x, min_y, &mut viewshed); // Top edge (min_y)
-        cast_ray(x, max_y, &mut viewshed); // Bottom edge
-        ray_count += 2;
-        if ray_count % 100 == 0 {
-             if let Some(p) = &progress {
-                 p.fetch_add(100, Ordering::Relaxed);
-             }
-        }
-    }
-    // Left and Right
-    for y in min_y..=max_y {
-         cast_ray(min_x, y, &mut viewshed); // Left edge
-         cast_ray(max_x, y, &mut viewshed); // Right edge
-         ray_count += 2;
-         if ray_count % 100 == 0 {
-             if let Some(p) = &progress {
-                 p.fetch_add(100, Ordering::Relaxed);
-             }
-         }
+    // Perimeter Traversal: one ray per border cell, spread over the thread pool
+    let endpoints: Vec<(isize, isize)> = (min_x..=max_x)
+        .flat_map(|x| [(x, min_y), (x, max_y)])
+        .chain((min_y..=max_y).flat_map(|y| [(min_x, y), (max_x, y)]))
+        .collect();
+    endpoints.par_iter().for_each(|&(end_x, end_y)| {
+        if is_cancelled(cancel) {
+            return;
+        }
+        cast_ray(end_x, end_y, &horizon);
+        if let Some(p) = &progress {
+            p.fetch_add(1, Ordering::Relaxed);
+        }
+    });
+
+    for (cell, value) in viewshed.horizon_map.iter_mut().zip(horizon) {
+        *cell = decode_angle(value.into_inner());
     }
+}
 
-    viewshed
+// Order-preserving u32 encoding of an f32, so that an atomic integer max is a float max
+fn encode_angle(angle: f32) -> u32 {
+    let bits = angle.to_bits();
+    if bits & 0x8000_0000 != 0 { !bits } else { bits | 0x8000_0000 }
+}
+
+fn decode_angle(encoded: u32) -> f32 {
+    let bits = if encoded & 0x8000_0000 != 0 { encoded & 0x7fff_ffff } else { !encoded };
+    f32::from_bits(bits)
+}
+
+// Grid geometry shared by R3 and XDraw. The radar sits at cell (cx, cy) like the
+// ray caster; distances are measured between cell indices.
+struct GridFrame {
+    cx: isize,
+    cy: isize,
+    cell_size: f64,
+    max_range: f64,
+    h_radar: f64,
+    two_k_r: f64,
+    origin: LatLon,
+}
+
+impl GridFrame {
+    fn new(viewshed: &Viewshed, radar: &Radar, max_range_m: f64, k_factor: f64) -> Self {
+        Self {
+            cx: viewshed.width as isize / 2,
+            cy: viewshed.height as isize / 2,
+            cell_size: viewshed.cell_size_m,
+            max_range: max_range_m,
+            h_radar: radar.antenna_altitude_amsl(),
+            two_k_r: 2.0 * k_factor * EARTH_RADIUS,
+            origin: radar.location,
+        }
+    }
+
+    // Distance (m) of a (possibly fractional) grid offset from the radar
+    fn distance(&self, dx: f64, dy: f64) -> f64 {
+        (dx * dx + dy * dy).sqrt() * self.cell_size
+    }
+
+    fn ground(&self, terrain: &(impl TerrainProvider + ?Sized), dx: isize, dy: isize) -> f64 {
+        let dist_x = dx as f64 * self.cell_size;
+        let dist_y = dy as f64 * self.cell_size;
+        terrain.get_altitude(LatLon {
+            latitude: self.origin.latitude + dist_y / 111111.0,
+            longitude: self.origin.longitude + dist_x / (111111.0 * self.origin.latitude.to_radians().cos()),
+            altitude: 0.0,
+        })
+    }
+
+    // Elevation angle (rad) of a terrain point under the effective-earth model
+    fn angle(&self, ground: f64, dist: f64) -> f64 {
+        ((ground - self.h_radar - dist * dist / self.two_k_r) / dist).atan()
+    }
+}
+
+fn compute_r3<T: TerrainProvider + Sync + ?Sized>(
+    viewshed: &mut Viewshed,
+    radar: &Radar,
+    terrain: &T,
+    max_range_m: f64,
+    k_factor: f64,
+    progress: Option<Arc<AtomicU32>>,
+    cancel: Option<&CancelToken>,
+) {
+    let frame = GridFrame::new(viewshed, radar, max_range_m, k_factor);
+    let (width, height) = (viewshed.width, viewshed.height);
+
+    // Ground altitude of every cell, sampled once
+    let mut ground = vec![0.0f64; width * height];
+    ground.par_chunks_mut(width).enumerate().for_each(|(y, row)| {
+        for (x, cell) in row.iter_mut().enumerate() {
+            *cell = frame.ground(terrain, x as isize - frame.cx, y as isize - frame.cy);
+        }
+    });
+    let ground_at = |x: isize, y: isize| ground[y as usize * width + x as usize];
+
+    // Every cell is independent: rows are handed out to the thread pool
+    viewshed.horizon_map.par_chunks_mut(width).enumerate().for_each(|(y, row)| {
+        if is_cancelled(cancel) {
+            return;
+        }
+        let y = y as isize;
+        for (x, cell) in row.iter_mut().enumerate() {
+            let x = x as isize;
+            let (dx, dy) = (x - frame.cx, y - frame.cy);
+            let dist = frame.distance(dx as f64, dy as f64);
+            if dist == 0.0 || dist > frame.max_range {
+                continue;
+            }
+
+            // Cross every intermediate column (x-major) or row (y-major) of the line
+            // of sight, interpolating the terrain between the two straddling cells.
+            let mut max_angle = frame.angle(ground_at(x, y), dist);
+            let major = dx.abs().max(dy.abs());
+            for i in 1..major {
+                let t = i as f64 / major as f64;
+                let (px, py) = (dx as f64 * t, dy as f64 * t);
+                let h = if dx.abs() >= dy.abs() {
+                    let gx = frame.cx + px.round() as isize;
+                    let fy = frame.cy as f64 + py;
+                    let (y0, frac) = (fy.floor() as isize, fy - fy.floor());
+                    let y1 = (y0 + 1).min(height as isize - 1);
+                    ground_at(gx, y0) * (1.0 - frac) + ground_at(gx, y1) * frac
+                } else {
+                    let gy = frame.cy + py.round() as isize;
+                    let fx = frame.cx as f64 + px;
+                    let (x0, frac) = (fx.floor() as isize, fx - fx.floor());
+                    let x1 = (x0 + 1).min(width as isize - 1);
+                    ground_at(x0, gy) * (1.0 - frac) + ground_at(x1, gy) * frac
+                };
+                max_angle = max_angle.max(frame.angle(h, dist * t));
+            }
+            *cell = max_angle as f32;
+        }
+        if let Some(p) = &progress {
+            p.fetch_add(1, Ordering::Relaxed);
+        }
+    });
+}
+
+fn compute_xdraw<T: TerrainProvider + Sync + ?Sized>(
+    viewshed: &mut Viewshed,
+    radar: &Radar,
+    terrain: &T,
+    max_range_m: f64,
+    k_factor: f64,
+    progress: Option<Arc<AtomicU32>>,
+    cancel: Option<&CancelToken>,
+) {
+    let frame = GridFrame::new(viewshed, radar, max_range_m, k_factor);
+    let (width, height) = (viewshed.width as isize, viewshed.height as isize);
+    let max_ring = frame.cx.max(frame.cy).max(width - 1 - frame.cx).max(height - 1 - frame.cy);
+
+    for ring in 1..=max_ring {
+        if is_cancelled(cancel) {
+            return;
+        }
+        // A ring only reads the previous one: its cells are evaluated in parallel,
+        // then written back in a fixed order.
+        let cells: Vec<(usize, f32)> = (-ring..=ring)
+            .into_par_iter()
+            .flat_map_iter(|k| {
+                // Cells at Chebyshev distance `ring`, along the four sides of the square.
+                // Corners belong to both a row and a column side; visit them once.
+                let sides = [(k, -ring), (k, ring), (-ring, k), (ring, k)];
+                let count = if k.abs() == ring { 2 } else { 4 };
+                sides.into_iter().take(count)
+            })
+            .filter_map(|(dx, dy)| {
+                let (x, y) = (frame.cx + dx, frame.cy + dy);
+                if x < 0 || x >= width || y < 0 || y >= height {
+                    return None;
+                }
+                let dist = frame.distance(dx as f64, dy as f64);
+                if dist > frame.max_range {
+                    return None;
+                }
+
+                let own = frame.angle(frame.ground(terrain, dx, dy), dist) as f32;
+                let inner = if ring == 1 {
+                    -std::f32::consts::FRAC_PI_2
+                } else {
+                    xdraw_inner_horizon(viewshed, frame.cx, frame.cy, dx, dy)
+                };
+                Some((y as usize * viewshed.width + x as usize, own.max(inner)))
+            })
+            .collect();
+
+        for (idx, angle) in cells {
+            viewshed.horizon_map[idx] = angle;
+        }
+        if let Some(p) = &progress {
+            p.fetch_add(1, Ordering::Relaxed);
+        }
+    }
+}
+
+// Horizon carried in from the previous ring: the line of sight to (dx, dy) crosses
+// the previous column (x-major) or row (y-major) between two cells, whose horizons
+// are interpolated.
+fn xdraw_inner_horizon(viewshed: &Viewshed, cx: isize, cy: isize, dx: isize, dy: isize) -> f32 {
+    let at = |x: isize, y: isize| viewshed.horizon_map[y as usize * viewshed.width + x as usize];
+    if dx.abs() >= dy.abs() {
+        let n = dx.abs();
+        let px = cx + dx - dx.signum();
+        let fy = dy as f32 * (n - 1) as f32 / n as f32;
+        let (y0, frac) = (fy.floor() as isize, fy - fy.floor());
+        let a = at(px, cy + y0);
+        if frac == 0.0 { a } else { a * (1.0 - frac) + at(px, cy + y0 + 1) * frac }
+    } else {
+        let n = dy.abs();
+        let py = cy + dy - dy.signum();
+        let fx = dx as f32 * (n - 1) as f32 / n as f32;
+        let (x0, frac) = (fx.floor() as isize, fx - fx.floor());
+        let a = at(cx + x0, py);
+        if frac == 0.0 { a } else { a * (1.0 - frac) + at(cx + x0 + 1, py) * frac }
+    }
+}
+
+/// Agreement of a viewshed with exact per-cell line of sight
+#[derive(Debug, Clone, Copy, Default)]
+pub struct ViewshedError {
+    pub samples: usize,
+    pub disagreements: usize,        // Cells where visible / shadowed differ
+    pub max_abs_error_deg: f64,      // Largest visibility margin error
+    pub mean_abs_error_deg: f64,
+}
+
+impl ViewshedError {
+    pub fn disagreement_ratio(&self) -> f64 {
+        if self.samples == 0 { 0.0 } else { self.disagreements as f64 / self.samples as f64 }
+    }
+}
+
+/// Compare the viewshed against `LosSystem` for a target `target_agl_m` above
+/// every `stride`-th cell in range. The margin error is the difference between
+/// the viewshed clearance (target angle minus horizon) and the LOS margin.
+pub fn compare_with_los<T: TerrainProvider>(
+    viewshed: &Viewshed,
+    radar: &Radar,
+    terrain: &T,
+    los: &crate::physics::los::LosSystem,
+    target_agl_m: f64,
+    stride: usize,
+) -> ViewshedError {
+    let two_k_r = 2.0 * crate::physics::refraction::effective_earth_radius(los.refraction);
+    let h_radar = radar.antenna_altitude_amsl();
+    let mut stats = ViewshedError::default();
+    let mut sum_error = 0.0;
+
+    for y in (0..viewshed.height).step_by(stride.max(1)) {
+        for x in (0..viewshed.width).step_by(stride.max(1)) {
+            let loc = viewshed.grid_to_latlon(x, y);
+            let (dist, _) = crate::physics::los::calculate_geodesic(radar.location, loc);
+            if dist < 2.0 * viewshed.cell_size_m || dist > viewshed.radius_m {
+                continue;
+            }
+            let Some(horizon) = viewshed.get_horizon_angle(loc) else { continue };
+
+            let target_alt = terrain.get_altitude(loc) + target_agl_m;
+            let target_angle = ((target_alt - h_radar - dist * dist / two_k_r) / dist).atan();
+            let margin_deg = (target_angle - horizon as f64).to_degrees();
+            let reference = los.check_visibility(radar, loc, target_agl_m, terrain);
+
+            stats.samples += 1;
+            if (margin_deg >= 0.0) != reference.is_visible {
+                stats.disagreements += 1;
+            }
+            let error = (margin_deg - reference.margin_deg).abs();
+            stats.max_abs_error_deg = stats.max_abs_error_deg.max(error);
+            sum_error += error;
+        }
+    }
+    if stats.samples > 0 {
+        stats.mean_abs_error_deg = sum_error / stats.samples as f64;
+    }
+    stats
 }
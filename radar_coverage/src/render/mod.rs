@@ -140,7 +140,8 @@ fn get_color(h: f32, min: f32, max: f32) -> [f32; 4] {
 }
 
 use bevy::render::render_resource::{Extent3d, TextureDimension, TextureFormat};
-use crate::coverage::CoverageTile;
+use crate::coverage::{CoverageTile, CoverageClass};
+use crate::coverage::vertical::VerticalCoverage;
 
 pub fn create_coverage_texture(tile: &CoverageTile) -> Image {
     let size = tile.size;
@@ -150,31 +151,156 @@ pub fn create_coverage_texture(tile: &CoverageTile) -> Image {
     // Green = Visible, Red = Invisible (or Transparent)
     // For overlay, we want visible to be Green transparent, Invisible to be Red transparent or hidden.
     
-    for y in 0..size {
-        for x in 0..size {
-            let idx = y * size + x;
-            if tile.data[idx] == 1 {
-                // Visible - Green
-                pixels.push(0);   // R
-                pixels.push(255); // G
-                pixels.push(0);   // B
-                pixels.push(100); // A (Semi-transparent)
-            } else if tile.data[idx] == 2 {
-                // Shadowed - Dark Red
-                pixels.push(128);
-                pixels.push(0);
-                pixels.push(0);
-                pixels.push(120); // A (Slightly more opaque)
+    for (idx, &class) in tile.data.iter().enumerate() {
+        let pixel = clutter_hatch(tile, idx).unwrap_or_else(|| coverage_class_color(CoverageClass::from_u8(class)));
+        pixels.extend_from_slice(&pixel);
+    }
+
+    Image::new(
+        Extent3d {
+            width: size as u32,
+            height: size as u32,
+            depth_or_array_layers: 1,
+        },
+        TextureDimension::D2,
+        pixels,
+        TextureFormat::Rgba8UnormSrgb,
+        bevy::render::render_asset::RenderAssetUsages::RENDER_WORLD, 
+    )
+}
+
+/// Diagonal hatching over detected cells where residual clutter exceeds noise
+pub const CLUTTER_HATCH_COLOR: [u8; 4] = [139, 90, 43, 200];
+const CLUTTER_HATCH_PERIOD: usize = 4;
+
+// Hatch pixel of a clutter-limited detection, None elsewhere
+fn clutter_hatch(tile: &CoverageTile, idx: usize) -> Option<[u8; 4]> {
+    let limited = tile.clutter_limited.get(idx).copied().unwrap_or(false);
+    let detected = crate::coverage::composite::is_detection(CoverageClass::from_u8(tile.data[idx]));
+    let on_line = (idx % tile.size + idx / tile.size) % CLUTTER_HATCH_PERIOD == 0;
+    (limited && detected && on_line).then_some(CLUTTER_HATCH_COLOR)
+}
+
+/// RGBA overlay colour for each coverage class
+pub fn coverage_class_color(class: CoverageClass) -> [u8; 4] {
+    match class {
+        CoverageClass::Visible => [0, 255, 0, 100],              // Green, semi-transparent
+        CoverageClass::Shadowed => [128, 0, 0, 120],             // Dark red, slightly more opaque
+        CoverageClass::ConeOfSilence => [255, 0, 255, 120],      // Magenta
+        CoverageClass::BelowMinRange => [255, 165, 0, 120],      // Orange
+        CoverageClass::BeyondInstrumented => [0, 128, 255, 90],  // Light blue
+        CoverageClass::BlindRange => [255, 255, 0, 120],         // Yellow
+        CoverageClass::RangeAmbiguous => [0, 200, 200, 100],     // Teal
+        CoverageClass::ClutterMasked => [139, 90, 43, 120],      // Brown
+        CoverageClass::Jammed => [255, 0, 0, 140],               // Bright red
+        CoverageClass::OutOfRange => [0, 0, 0, 0],               // Transparent
+    }
+}
+
+/// RGBA pixels of a vertical coverage diagram: range left to right, height
+/// bottom to top, terrain in grey and coverage classes opaque over a dark sky.
+pub fn vertical_coverage_pixels(diagram: &VerticalCoverage) -> Vec<u8> {
+    let (width, height) = (diagram.width(), diagram.height());
+    let mut pixels = Vec::with_capacity(width * height * 4);
+
+    for row in (0..height).rev() {
+        for col in 0..width {
+            let pixel = if diagram.heights_m[row] < diagram.terrain_m[col] {
+                [110, 110, 110, 255]
             } else {
-                // Out of range (0) - Transparent
-                pixels.push(0);
-                pixels.push(0);
-                pixels.push(0);
-                pixels.push(0); // Invisible
-            }
+                match diagram.class_at(col, row) {
+                    CoverageClass::OutOfRange => [20, 20, 30, 255],
+                    class => {
+                        let [r, g, b, _] = coverage_class_color(class);
+                        [r, g, b, 255]
+                    }
+                }
+            };
+            pixels.extend_from_slice(&pixel);
+        }
+    }
+    pixels
+}
+
+/// Red (0) through yellow to green (1)
+pub fn ramp_color(t: f32) -> [u8; 4] {
+    let t = t.clamp(0.0, 1.0);
+    let (r, g) = if t < 0.5 { (1.0, t * 2.0) } else { (2.0 - t * 2.0, 1.0) };
+    [(r * 255.0) as u8, (g * 255.0) as u8, 0, 140]
+}
+
+/// Colour ramp for minimum visible altitudes: green (low) through yellow to red
+/// at `max_m`; cells with no detectable altitude use the shadow colour.
+pub fn min_altitude_color(altitude_m: Option<f32>, max_m: f32) -> [u8; 4] {
+    let Some(altitude) = altitude_m else {
+        return coverage_class_color(CoverageClass::Shadowed);
+    };
+    ramp_color(1.0 - altitude / max_m)
+}
+
+/// Per-cell quantity the detection and intercept overlays are coloured by
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub enum CoverageColoring {
+    #[default]
+    Class,     // CoverageClass colours
+    Clearance, // Elevation above the terrain horizon
+    SnrMargin, // SNR above the required SNR
+    Pd,        // Probability of detection
+}
+
+impl CoverageColoring {
+    pub fn label(&self) -> &'static str {
+        match self {
+            CoverageColoring::Class => "Coverage class",
+            CoverageColoring::Clearance => "Terrain clearance (°)",
+            CoverageColoring::SnrMargin => "SNR margin (dB)",
+            CoverageColoring::Pd => "Probability of detection",
         }
     }
 
+    /// Values mapped to the red and green ends of the ramp
+    pub fn range(&self) -> (f32, f32) {
+        match self {
+            CoverageColoring::Class => (0.0, 1.0),
+            CoverageColoring::Clearance => (0.0, 2.0),
+            CoverageColoring::SnrMargin => (0.0, 20.0),
+            CoverageColoring::Pd => (0.0, 1.0),
+        }
+    }
+
+    /// The tile's value at `idx`, None if the tile lacks it or it was not evaluated
+    pub fn value(&self, tile: &CoverageTile, idx: usize) -> Option<f32> {
+        let value = match self {
+            CoverageColoring::Class => return None,
+            CoverageColoring::Clearance => tile.clearance_deg.get(idx),
+            CoverageColoring::SnrMargin => tile.snr_margin_db.get(idx),
+            CoverageColoring::Pd => tile.pd.get(idx),
+        };
+        value.copied().filter(|v| !v.is_nan())
+    }
+}
+
+/// Overlay coloured by `coloring`: cells carrying the value on the red to green
+/// ramp, the others (not evaluated, or no detection model for Pd) by class.
+/// Out-of-range cells stay transparent.
+pub fn create_coverage_field_texture(tile: &CoverageTile, coloring: CoverageColoring) -> Image {
+    if coloring == CoverageColoring::Class {
+        return create_coverage_texture(tile);
+    }
+    let size = tile.size;
+    let (low, high) = coloring.range();
+    let mut pixels = Vec::with_capacity(size * size * 4);
+
+    for (idx, &class) in tile.data.iter().enumerate() {
+        let class = CoverageClass::from_u8(class);
+        let pixel = match coloring.value(tile, idx) {
+            Some(value) if class != CoverageClass::OutOfRange => ramp_color((value - low) / (high - low)),
+            _ => coverage_class_color(class),
+        };
+        let pixel = clutter_hatch(tile, idx).unwrap_or(pixel);
+        pixels.extend_from_slice(&pixel);
+    }
+
     Image::new(
         Extent3d {
             width: size as u32,
@@ -184,6 +310,106 @@ pub fn create_coverage_texture(tile: &CoverageTile) -> Image {
         TextureDimension::D2,
         pixels,
         TextureFormat::Rgba8UnormSrgb,
-        bevy::render::render_asset::RenderAssetUsages::RENDER_WORLD, 
+        bevy::render::render_asset::RenderAssetUsages::RENDER_WORLD,
+    )
+}
+
+/// Minimum visible altitude overlay, AGL or AMSL. Out-of-range cells are transparent.
+pub fn create_min_altitude_texture(tile: &CoverageTile, above_ground: bool, max_m: f32) -> Image {
+    let size = tile.size;
+    let mut pixels = Vec::with_capacity(size * size * 4);
+
+    for (idx, &class) in tile.data.iter().enumerate() {
+        let pixel = match CoverageClass::from_u8(class) {
+            CoverageClass::Visible | CoverageClass::Shadowed => {
+                let altitude = if above_ground {
+                    tile.min_altitude_agl(idx)
+                } else {
+                    tile.min_altitude_amsl.get(idx).copied().filter(|a| !a.is_nan())
+                };
+                min_altitude_color(altitude, max_m)
+            }
+            other => coverage_class_color(other),
+        };
+        pixels.extend_from_slice(&pixel);
+    }
+
+    Image::new(
+        Extent3d {
+            width: size as u32,
+            height: size as u32,
+            depth_or_array_layers: 1,
+        },
+        TextureDimension::D2,
+        pixels,
+        TextureFormat::Rgba8UnormSrgb,
+        bevy::render::render_asset::RenderAssetUsages::RENDER_WORLD,
+    )
+}
+
+use crate::coverage::composite::{CompositeMode, CompositeTile, NO_RADAR};
+
+/// Distinct colour per radar of the network, for the best radar layer
+pub fn radar_palette_color(index: u8) -> [u8; 4] {
+    const PALETTE: [[u8; 3]; 8] = [
+        [0, 200, 0],     // Green
+        [0, 120, 255],   // Blue
+        [255, 140, 0],   // Orange
+        [200, 0, 200],   // Purple
+        [0, 200, 200],   // Teal
+        [255, 220, 0],   // Yellow
+        [255, 60, 60],   // Red
+        [150, 100, 50],  // Brown
+    ];
+    let [r, g, b] = PALETTE[index as usize % PALETTE.len()];
+    [r, g, b, 110]
+}
+
+/// Colour of a composite cell: covered cells in green (or in their best radar's
+/// colour), cells seen by fewer than N radars in amber, the rest transparent.
+/// The redundancy modes use the red / amber / green ramp of `redundancy_color`.
+pub fn composite_color(tile: &CompositeTile, idx: usize, mode: CompositeMode) -> [u8; 4] {
+    let count = tile.radar_count[idx];
+    if count == 0 {
+        return [0, 0, 0, 0];
+    }
+    match mode {
+        CompositeMode::BestRadar if tile.best_radar[idx] != NO_RADAR => radar_palette_color(tile.best_radar[idx]),
+        CompositeMode::Redundancy => redundancy_color(count),
+        CompositeMode::WithoutRadar(radar) if tile.depends_on(idx, radar) => coverage_class_color(CoverageClass::Jammed),
+        _ if tile.covered(idx, mode) => coverage_class_color(CoverageClass::Visible),
+        _ => [255, 170, 0, 100],
+    }
+}
+
+/// Single covered cells in red, double in amber, three or more in green
+pub fn redundancy_color(count: u8) -> [u8; 4] {
+    match count {
+        0 => [0, 0, 0, 0],
+        1 => [230, 40, 40, 120],
+        2 => [255, 170, 0, 100],
+        _ => coverage_class_color(CoverageClass::Visible),
+    }
+}
+
+/// Fused network overlay, one quad per tile instead of one per radar
+pub fn create_composite_texture(tile: &CompositeTile, mode: CompositeMode) -> Image {
+    let size = tile.size;
+    let mut pixels = Vec::with_capacity(size * size * 4);
+
+    for idx in 0..tile.radar_count.len() {
+        pixels.extend_from_slice(&composite_color(tile, idx, mode));
+    }
+
+    Image::new(
+        Extent3d {
+            width: size as u32,
+            height: size as u32,
+            depth_or_array_layers: 1,
+        },
+        TextureDimension::D2,
+        pixels,
+        TextureFormat::Rgba8UnormSrgb,
+        bevy::render::render_asset::RenderAssetUsages::RENDER_WORLD,
     )
 }
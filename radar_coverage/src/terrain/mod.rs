@@ -7,6 +7,9 @@ use crate::physics::los::TerrainProvider;
 use std::sync::{Arc, Mutex};
 use lru::LruCache;
 use std::num::NonZeroUsize;
+use std::hash::{Hash, Hasher};
+use std::ops::RangeInclusive;
+use crate::cache::StableHasher;
 
 pub const SRTM3_SIZE: usize = 1201;
 pub const SRTM1_SIZE: usize = 3601;
@@ -88,12 +91,39 @@ impl TerrainLoader {
         Self { assets_path }
     }
 
-    pub fn load_tile(&self, lat: i32, lon: i32) -> Result<TerrainTile> {
+    pub fn tile_path(&self, lat: i32, lon: i32) -> PathBuf {
         let filename = format!("{}{:02}{}{:03}.hgt", 
             if lat >= 0 { "N" } else { "S" }, lat.abs(),
             if lon >= 0 { "E" } else { "W" }, lon.abs()
         );
-        let path = self.assets_path.join(&filename);
+        self.assets_path.join(&filename)
+    }
+
+    /// Identity of the dataset over a block of tiles: size and modification time of
+    /// every file, missing tiles (flat fallback) included. Changes when a tile is
+    /// added, replaced or removed.
+    pub fn fingerprint(&self, lats: RangeInclusive<i32>, lons: RangeInclusive<i32>) -> u64 {
+        let mut hasher = StableHasher::default();
+        for lat in lats {
+            for lon in lons.clone() {
+                (lat, lon).hash(&mut hasher);
+                match std::fs::metadata(self.tile_path(lat, lon)) {
+                    Ok(metadata) => {
+                        metadata.len().hash(&mut hasher);
+                        let modified = metadata.modified().ok()
+                            .and_then(|t| t.duration_since(std::time::UNIX_EPOCH).ok())
+                            .map_or(0, |d| d.as_secs());
+                        modified.hash(&mut hasher);
+                    }
+                    Err(_) => "flat".hash(&mut hasher),
+                }
+            }
+        }
+        hasher.finish()
+    }
+
+    pub fn load_tile(&self, lat: i32, lon: i32) -> Result<TerrainTile> {
+        let path = self.tile_path(lat, lon);
 
         if !path.exists() {
             // Fallback: Return flat tile at 0m
@@ -143,6 +173,15 @@ impl TerrainManager {
         }
     }
 
+    /// Terrain fingerprint of the tiles within `radius_m` of `center`
+    pub fn fingerprint(&self, center: LatLon, radius_m: f64) -> u64 {
+        let d_lat = radius_m / 111111.0;
+        let d_lon = radius_m / (111111.0 * center.latitude.to_radians().cos().max(0.01));
+        let lats = (center.latitude - d_lat).floor() as i32..=(center.latitude + d_lat).floor() as i32;
+        let lons = (center.longitude - d_lon).floor() as i32..=(center.longitude + d_lon).floor() as i32;
+        self.loader.fingerprint(lats, lons)
+    }
+
     pub fn get_tile(&self, lat: i32, lon: i32) -> Result<Arc<TerrainTile>> {
         {
             let mut cache = self.cache.lock().unwrap();
@@ -2,6 +2,7 @@ use crate::geo::LatLon;
 use crate::physics::los::{calculate_geodesic, LosSystem, TerrainProvider};
 use crate::physics::refraction::RefractionParams;
 use crate::io::Radar;
+use crate::jobs::JobContext;
 
 
 struct MockTerrain {
@@ -14,6 +15,25 @@ impl TerrainProvider for MockTerrain {
     }
 }
 
+impl crate::coverage::CoverageTile {
+    /// Tile fixture: the given classes, nothing evaluated, no optional layers
+    fn from_classes(lat_idx: i32, lon_idx: i32, size: usize, data: &[crate::coverage::CoverageClass]) -> Self {
+        assert_eq!(data.len(), size * size);
+        Self {
+            lat_idx,
+            lon_idx,
+            size,
+            data: data.iter().map(|&c| c as u8).collect(),
+            clearance_deg: vec![f32::NAN; size * size],
+            snr_margin_db: vec![f32::NAN; size * size],
+            pd: Vec::new(),
+            clutter_limited: Vec::new(),
+            min_altitude_amsl: Vec::new(),
+            ground_amsl: Vec::new(),
+        }
+    }
+}
+
 #[test]
 fn test_geodesic_distance() {
     let p1 = LatLon { latitude: 0.0, longitude: 0.0, altitude: 0.0 };
@@ -33,7 +53,8 @@ fn test_los_flat_earth_blocked() {
         name: "Test".to_string(),
         location: LatLon { latitude: 0.0, longitude: 0.0, altitude: 10.0 }, // 10m tower
         antenna_height_agl: 0.0,
-        tx_power_w: 0.0, gain_dbi: 0.0, frequency_mhz: 0.0, system_loss_db: 0.0, snr_threshold_db: 0.0, azimuth_sector: None, elevation_sector: None
+        tx_power_w: 0.0, gain_dbi: 0.0, frequency_mhz: 0.0, system_loss_db: 0.0, snr_threshold_db: 0.0, azimuth_sector: None, elevation_sector: None,
+        ..Default::default()
     };
     
     let target = LatLon { latitude: 1.0, longitude: 0.0, altitude: 0.0 }; // ~111km away
@@ -56,7 +77,8 @@ fn test_los_close_visible() {
         name: "Test".to_string(),
         location: LatLon { latitude: 0.0, longitude: 0.0, altitude: 10.0 }, 
         antenna_height_agl: 0.0,
-        tx_power_w: 0.0, gain_dbi: 0.0, frequency_mhz: 0.0, system_loss_db: 0.0, snr_threshold_db: 0.0, azimuth_sector: None, elevation_sector: None
+        tx_power_w: 0.0, gain_dbi: 0.0, frequency_mhz: 0.0, system_loss_db: 0.0, snr_threshold_db: 0.0, azimuth_sector: None, elevation_sector: None,
+        ..Default::default()
     };
     
     let target = LatLon { latitude: 0.0001, longitude: 0.0, altitude: 0.0 }; // Very close
@@ -69,3 +91,1004 @@ fn test_los_close_visible() {
     
     assert!(result.is_visible);
 }
+
+#[test]
+fn test_detection_shnidman_matches_albersheim() {
+    use crate::physics::detection::{albersheim_snr_db, shnidman_snr_db, SwerlingModel};
+
+    // Both approximations should agree for a steady target (~13.1 dB for Pd 0.9, Pfa 1e-6)
+    let albersheim = albersheim_snr_db(0.9, 1e-6, 1);
+    let shnidman = shnidman_snr_db(0.9, 1e-6, 1, SwerlingModel::Swerling0);
+    assert!((albersheim - 13.1).abs() < 0.5);
+    assert!((albersheim - shnidman).abs() < 0.5);
+
+    // Fluctuating targets need more SNR for high Pd
+    let swerling1 = shnidman_snr_db(0.9, 1e-6, 1, SwerlingModel::Swerling1);
+    assert!(swerling1 > shnidman + 5.0);
+}
+
+#[test]
+fn test_detection_pd_inverts_required_snr() {
+    use crate::physics::detection::{DetectionParams, SwerlingModel};
+
+    let params = DetectionParams { pd: 0.8, pfa: 1e-6, n_pulses: 10, swerling: SwerlingModel::Swerling1 };
+    let snr = params.required_snr_db();
+    assert!((params.probability_of_detection(snr) - 0.8).abs() < 1e-3);
+    assert!(params.probability_of_detection(snr - 3.0) < 0.8);
+    assert_eq!(params.probability_of_detection(-30.0), 0.0);
+}
+
+#[test]
+fn test_antenna_pattern_shapes() {
+    use crate::physics::antenna::{PatternShape, PatternTable};
+
+    let sinc = PatternShape::Sinc { beamwidth_deg: 2.0 };
+    assert!(sinc.gain_db(0.0).abs() < 1e-9);
+    assert!((sinc.gain_db(1.0) + 3.0).abs() < 0.1); // -3 dB at half beamwidth
+
+    // csc^2: gain falls 6 dB per doubling of elevation in the shaped region
+    let csc = PatternShape::CosecantSquared { beamwidth_deg: 4.0, max_elevation_deg: 40.0 };
+    assert!((csc.gain_db(10.0) - csc.gain_db(20.0) - 20.0 * (20f64.to_radians().sin() / 10f64.to_radians().sin()).log10()).abs() < 1e-6);
+    assert!(csc.gain_db(60.0) < csc.gain_db(40.0));
+
+    let table = PatternShape::Table(PatternTable { angles_deg: vec![0.0, 10.0], gains_db: vec![0.0, -20.0] });
+    assert!((table.gain_db(5.0) + 10.0).abs() < 1e-9);
+    assert!((table.gain_db(50.0) + 20.0).abs() < 1e-9);
+}
+
+#[test]
+fn test_antenna_pattern_csv_round_trip() {
+    use crate::io::load_radars_from_json;
+    use crate::physics::antenna::{PatternShape, PatternTable};
+
+    let dir = std::env::temp_dir().join(format!("rc_pattern_test_{}", std::process::id()));
+    std::fs::create_dir_all(&dir).unwrap();
+    let table = PatternTable { angles_deg: vec![-10.0, 0.0, 2.5, 10.0], gains_db: vec![-25.0, 0.0, -3.5, -25.0] };
+    table.save_csv(dir.join("azimuth.csv")).unwrap();
+    assert_eq!(PatternTable::from_csv(dir.join("azimuth.csv")).unwrap(), table);
+
+    // Radar files name the pattern relative to themselves; it is loaded with the radars
+    let radar = Radar { name: "Tabulated".to_string(), azimuth_pattern_csv: Some("azimuth.csv".into()), ..Default::default() };
+    std::fs::write(dir.join("radars.json"), serde_json::to_string(&vec![radar]).unwrap()).unwrap();
+    let radars = load_radars_from_json(dir.join("radars.json").to_str().unwrap()).unwrap();
+    assert_eq!(radars[0].antenna.azimuth, PatternShape::Table(table));
+    assert_eq!(radars[0].azimuth_beamwidth_deg(), 5.0);
+
+    // A missing file is an error, not a silent omni pattern
+    let missing = Radar { azimuth_pattern_csv: Some("missing.csv".into()), ..Default::default() };
+    std::fs::write(dir.join("radars.json"), serde_json::to_string(&vec![missing]).unwrap()).unwrap();
+    assert!(load_radars_from_json(dir.join("radars.json").to_str().unwrap()).is_err());
+    std::fs::remove_dir_all(&dir).unwrap();
+}
+
+#[test]
+fn test_radar_sector_blanking() {
+    let radar = Radar {
+        name: "Sector".to_string(),
+        gain_dbi: 30.0,
+        azimuth_sector: Some((300.0, 60.0)),
+        elevation_sector: Some((-2.0, 20.0)),
+        ..Default::default()
+    };
+
+    assert_eq!(radar.gain_towards_dbi(0.0, 0.0), Some(30.0));
+    assert_eq!(radar.gain_towards_dbi(-30.0, 0.0), Some(30.0));
+    assert!(radar.gain_towards_dbi(90.0, 0.0).is_none());
+    assert!(radar.gain_towards_dbi(0.0, 30.0).is_none());
+}
+
+#[test]
+fn test_coverage_limits_classes() {
+    use crate::coverage::{compute_coverage_tile, CoverageClass, CoverageRequest};
+    use crate::physics::viewshed::Viewshed;
+    use crate::terrain::{TerrainLoader, TerrainManager};
+    use std::sync::Arc;
+
+    // Missing HGT files fall back to flat sea-level tiles
+    let terrain = Arc::new(TerrainManager::new(TerrainLoader::new("/nonexistent".into()), 4));
+    let radar = Radar {
+        name: "Limits".to_string(),
+        location: LatLon { latitude: 45.5, longitude: 5.5, altitude: 20.0 },
+        tx_power_w: 150000.0, gain_dbi: 42.0, frequency_mhz: 3100.0, system_loss_db: 3.0, snr_threshold_db: 13.0,
+        min_range_m: Some(5_000.0),
+        max_elevation_deg: Some(10.0),
+        instrumented_range_m: Some(40_000.0),
+        ..Default::default()
+    };
+    let viewshed: Arc<crate::physics::viewshed::HorizonGrid> = Arc::new(Viewshed::new(radar.location, 60_000.0, 1000.0).into()); // Nothing masked
+
+    let step = 10;
+    let request = CoverageRequest { target: crate::physics::target::TargetModel::isotropic("Fighter", 5.0), target_altitude: 3000.0, step_size: step, ..Default::default() };
+    let ctx = JobContext::default();
+    let tile = compute_coverage_tile(radar.clone(), terrain.clone(), viewshed.clone(), 45, 5, &request, &ctx).unwrap();
+    // One progress unit per row
+    assert_eq!(ctx.progress.done.load(std::sync::atomic::Ordering::Relaxed), request.progress_total());
+    assert_eq!(request.progress_total() as usize, tile.size);
+    ctx.cancel.cancel();
+    assert!(compute_coverage_tile(radar.clone(), terrain, viewshed, 45, 5, &request, &ctx).is_none());
+    let index_at = |lat: f64, lon: f64| {
+        let y = (((46.0 - lat) * 1200.0) / step as f64).round() as usize;
+        let x = (((lon - 5.0) * 1200.0) / step as f64).round() as usize;
+        y * tile.size + x
+    };
+    let class_at = |lat: f64, lon: f64| CoverageClass::from_u8(tile.data[index_at(lat, lon)]);
+
+    assert_eq!(class_at(45.5, 5.5), CoverageClass::BelowMinRange);
+    assert_eq!(class_at(45.6, 5.5), CoverageClass::ConeOfSilence); // ~11 km, 15 deg
+    assert_eq!(class_at(45.75, 5.5), CoverageClass::Visible);      // ~28 km
+    assert_eq!(class_at(45.95, 5.5), CoverageClass::BeyondInstrumented); // ~50 km
+
+    // The SNR margin is the radar equation at the cell's range, the clearance is angular
+    let idx = index_at(45.75, 5.5);
+    let (dist, _) = calculate_geodesic(radar.location, LatLon { latitude: 45.75, longitude: 5.5, altitude: 0.0 });
+    let expected = crate::physics::radar_eq::calculate_snr_db(&radar, dist, 5.0) - radar.required_snr_db();
+    assert!((tile.snr_margin_db[idx] as f64 - expected).abs() < 0.01, "{} vs {}", tile.snr_margin_db[idx], expected);
+    assert!(tile.clearance_deg[idx] > 90.0); // Nothing masked: horizon at -90°
+    assert!(tile.snr_margin_db[index_at(45.5, 5.5)].is_nan()); // Not evaluated inside the minimum range
+    assert!(tile.pd.is_empty()); // No detection model
+}
+
+#[test]
+fn test_pulse_eclipsing_and_ambiguity() {
+    use crate::physics::pulse::PulseWaveform;
+
+    // 1 kHz PRF -> ~150 km unambiguous range, 10 us pulse -> 1.5 km extent
+    let waveform = PulseWaveform { prf_hz: 1000.0, pulse_width_us: 10.0 };
+    let ru = waveform.unambiguous_range_m();
+    assert!((ru - 149_896.0).abs() < 1.0);
+    assert!((waveform.duty_cycle() - 0.01).abs() < 1e-12);
+
+    assert_eq!(waveform.eclipsing_fraction(50_000.0), 1.0);
+    assert!(waveform.eclipsing_fraction(ru + 750.0) < 0.51); // Half-eclipsed behind the next pulse
+    assert!(waveform.eclipsing_loss_db(ru + 100.0) > 10.0);
+    assert!(waveform.is_range_ambiguous(ru + 1.0));
+
+    let bands = waveform.blind_range_bands(400_000.0);
+    assert_eq!(bands.len(), 3);
+    assert!(bands[1].0 < ru && bands[1].1 > ru);
+}
+
+#[test]
+fn test_clutter_constant_gamma() {
+    use crate::physics::clutter::{ClutterModel, SigmaZeroModel, TerrainClass};
+
+    let model = ClutterModel::default();
+    let grazing = 1f64.to_radians();
+    let flat = model.sigma0_db(TerrainClass::Flatland, grazing);
+    assert!((flat - (-18.0 + 10.0 * grazing.sin().log10())).abs() < 1e-9);
+    assert!(model.sigma0_db(TerrainClass::Mountains, grazing) > flat);
+
+    // Rougher sea gives stronger returns
+    let calm = ClutterModel { sea_state: 1, ..ClutterModel::default() };
+    let rough = ClutterModel { sea_state: 5, ..ClutterModel::default() };
+    assert!(rough.sigma0_db(TerrainClass::Sea, grazing) > calm.sigma0_db(TerrainClass::Sea, grazing));
+
+    let table = SigmaZeroModel::Table { grazing_deg: vec![0.0, 10.0], sigma0_db: vec![-40.0, -20.0] };
+    assert!((table.sigma0_db(5f64.to_radians()) + 30.0).abs() < 1e-9);
+}
+
+#[test]
+fn test_self_protection_burn_through() {
+    use crate::io::{Jammer, JammerGeometry, JammingType};
+    use crate::physics::radar_eq::{calculate_jsr_db, self_protection_burn_through_range, max_detection_range};
+
+    let radar = Radar {
+        name: "EA".to_string(),
+        tx_power_w: 150000.0, gain_dbi: 42.0, frequency_mhz: 3100.0, system_loss_db: 3.0, snr_threshold_db: 13.0,
+        ..Default::default()
+    };
+    let jammer = Jammer {
+        name: "SPJ".to_string(),
+        erp_w: 100.0,
+        bandwidth_hz: 10_000_000.0,
+        center_frequency_mhz: 3100.0,
+        jamming_type: JammingType::Spot,
+        geometry: JammerGeometry::SelfProtection,
+        ..Default::default()
+    };
+
+    let rcs = 5.0;
+    let burn_through = self_protection_burn_through_range(&radar, &jammer, rcs);
+    assert!(burn_through > 0.0 && burn_through < max_detection_range(&radar, rcs));
+
+    // At burn-through, S/J (noise neglected) must be at least the required SNR
+    let jsr = calculate_jsr_db(&radar, &jammer, burn_through, rcs, radar.gain_dbi, burn_through, radar.gain_dbi);
+    assert!(-jsr >= radar.required_snr_db() - 0.01);
+
+    // Off-frequency barrage jamming has no effect
+    let barrage = Jammer { jamming_type: JammingType::NoiseBarrage, center_frequency_mhz: 9000.0, ..jammer };
+    assert!((self_protection_burn_through_range(&radar, &barrage, rcs) - max_detection_range(&radar, rcs)).abs() < 1.0);
+}
+
+#[test]
+fn test_esm_intercept_range() {
+    use crate::physics::esm::{EsmReceiver, intercept_power_dbm, intercept_range};
+
+    let radar = Radar {
+        name: "Emitter".to_string(),
+        tx_power_w: 1000.0, gain_dbi: 30.0, frequency_mhz: 3000.0, system_loss_db: 0.0,
+        ..Default::default()
+    };
+    let receiver = EsmReceiver::default();
+
+    let range = intercept_range(&radar, &receiver);
+    let power = intercept_power_dbm(&radar, &receiver, range, 0.0, 0.0).unwrap();
+    assert!((power - receiver.sensitivity_dbm).abs() < 1e-6);
+
+    // One-way link: doubling the distance costs 6 dB
+    let far = intercept_power_dbm(&radar, &receiver, 2.0 * range, 0.0, 0.0).unwrap();
+    assert!((power - far - 6.02).abs() < 0.01);
+}
+
+#[test]
+fn test_bistatic_reduces_to_monostatic() {
+    use crate::physics::bistatic::{bistatic_range_product, calculate_bistatic_snr_db, cassini_oval};
+    use crate::physics::radar_eq::{calculate_snr_db, max_detection_range};
+
+    let radar = Radar {
+        name: "Mono".to_string(),
+        location: LatLon { latitude: 45.0, longitude: 5.0, altitude: 0.0 },
+        tx_power_w: 150000.0, gain_dbi: 42.0, frequency_mhz: 3100.0, system_loss_db: 3.0, snr_threshold_db: 13.0,
+        ..Default::default()
+    };
+
+    let snr_bi = calculate_bistatic_snr_db(&radar, &radar, 80_000.0, 80_000.0, 5.0, radar.gain_dbi, radar.gain_dbi);
+    assert!((snr_bi - calculate_snr_db(&radar, 80_000.0, 5.0)).abs() < 1e-9);
+    assert!((bistatic_range_product(&radar, &radar, 5.0).sqrt() - max_detection_range(&radar, 5.0)).abs() < 1.0);
+
+    // Points on the Cassini oval keep Rt * Rr constant
+    let rx = LatLon { latitude: 45.0, longitude: 6.0, altitude: 0.0 };
+    let product = 100_000.0 * 100_000.0;
+    let ovals = cassini_oval(radar.location, rx, product, 64);
+    assert_eq!(ovals.len(), 1);
+    for p in &ovals[0] {
+        let (rt, _) = calculate_geodesic(radar.location, *p);
+        let (rr, _) = calculate_geodesic(rx, *p);
+        assert!((rt * rr / product - 1.0).abs() < 0.02);
+    }
+
+    // Long baseline splits into one oval around each site
+    assert_eq!(cassini_oval(radar.location, rx, 20_000.0 * 20_000.0, 64).len(), 2);
+}
+
+#[test]
+fn test_airborne_platform_horizon_and_orbit() {
+    use crate::io::{Orbit, Platform};
+
+    // Same 10 m tower as test_los_flat_earth_blocked, but carried by an AEW aircraft
+    let center = LatLon { latitude: 0.0, longitude: 0.0, altitude: 0.0 };
+    let radar = Radar {
+        name: "AEW".to_string(),
+        location: center,
+        platform: Platform::Airborne {
+            altitude_amsl_m: 9000.0,
+            orbit: Some(Orbit { center, heading_deg: 90.0, length_m: 100_000.0, width_m: 20_000.0 }),
+        },
+        ..Default::default()
+    };
+    assert_eq!(radar.antenna_altitude_amsl(), 9000.0);
+
+    let los = LosSystem::new(RefractionParams { k_factor: 1.33 });
+    let target = LatLon { latitude: 1.0, longitude: 0.0, altitude: 0.0 };
+    assert!(los.check_visibility(&radar, target, 10.0, &MockTerrain { altitude: 0.0 }).is_visible);
+
+    // Sampled orbit points lie on the racetrack, within half a leg plus the turn radius
+    let positions = radar.orbit_positions(16);
+    assert_eq!(positions.len(), 16);
+    for p in &positions {
+        let (dist, _) = calculate_geodesic(center, p.location);
+        assert!((9_900.0..=60_100.0).contains(&dist), "orbit point {} m from centre", dist);
+        assert_eq!(p.antenna_altitude_amsl(), 9000.0);
+    }
+}
+
+#[test]
+fn test_target_model_aspect_and_band() {
+    use crate::physics::target::{builtin_targets, AspectMode, FrequencyBand};
+
+    assert_eq!(FrequencyBand::from_mhz(3100.0), FrequencyBand::S);
+    assert_eq!(FrequencyBand::from_mhz(150.0), FrequencyBand::VHF);
+
+    let fighter = builtin_targets().into_iter().find(|t| t.name == "4G Fighter").unwrap();
+
+    // Heading north: a radar due south sees the tail, a radar due east the beam
+    let heading = fighter.signature(3100.0, AspectMode::Heading(0.0));
+    let tail = heading.rcs_sqm(0.0, 0.0);   // Radar looks north at the target
+    let beam = heading.rcs_sqm(270.0, 0.0); // Radar looks west at the target
+    assert!((10.0 * tail.log10() - 8.0).abs() < 1e-9);
+    assert!((10.0 * beam.log10() - 15.0).abs() < 1e-9);
+
+    // Worst case is the nose, the average lies between nose and beam
+    let worst = fighter.signature(3100.0, AspectMode::WorstCase).rcs_sqm(123.0, 0.0);
+    let average = fighter.signature(3100.0, AspectMode::Average).rcs_sqm(123.0, 0.0);
+    assert!((10.0 * worst.log10() - 3.0).abs() < 1e-9);
+    assert!(average > worst && average < beam);
+
+    // Stealth shaping loses its effect at VHF
+    let stealth = builtin_targets().into_iter().find(|t| t.name == "5G Stealth Fighter").unwrap();
+    assert!(stealth.nose_rcs_sqm(150.0) > 10.0 * stealth.nose_rcs_sqm(9000.0));
+}
+
+#[test]
+fn test_target_library_skips_malformed_files() {
+    use crate::io::load_target_library;
+    use crate::physics::target::TargetModel;
+
+    let dir = std::env::temp_dir().join(format!("targets_test_{}", std::process::id()));
+    std::fs::create_dir_all(&dir).unwrap();
+    let drone = TargetModel::isotropic("Test Drone", 0.01);
+    std::fs::write(dir.join("a_broken.json"), "[{\"name\": ").unwrap();
+    std::fs::write(dir.join("b_drones.json"), serde_json::to_string(&vec![drone.clone()]).unwrap()).unwrap();
+
+    let targets = load_target_library(&dir).unwrap();
+    assert!(targets.contains(&drone));
+    assert!(targets.len() > 1); // Built-ins kept
+    std::fs::remove_dir_all(&dir).unwrap();
+}
+
+#[test]
+fn test_vertical_coverage_smooth_earth() {
+    use crate::coverage::vertical::{compute_vertical_coverage, VerticalCoverageParams};
+    use crate::coverage::{CoverageClass, CoverageRequest};
+    use crate::physics::target::TargetModel;
+
+    let radar = Radar {
+        name: "Vertical".to_string(),
+        location: LatLon { latitude: 45.0, longitude: 5.0, altitude: 20.0 },
+        tx_power_w: 150000.0, gain_dbi: 42.0, frequency_mhz: 3100.0, system_loss_db: 3.0, snr_threshold_db: 13.0,
+        ..Default::default()
+    };
+    let request = CoverageRequest { target: TargetModel::isotropic("Fighter", 5.0), ..Default::default() };
+    let params = VerticalCoverageParams { max_range_m: 150_000.0, max_height_m: 6_000.0, range_step_m: 1_000.0, ..Default::default() };
+    let diagram = compute_vertical_coverage(&radar, &MockTerrain { altitude: 0.0 }, &request, &params);
+
+    assert_eq!(diagram.width(), 150);
+    assert_eq!(diagram.height(), 61);
+    // 100 km is beyond the radio horizon of a 20 m antenna for a 100 m target (~59 km)
+    assert_eq!(diagram.class_at(99, 1), CoverageClass::Shadowed);
+    assert_eq!(diagram.class_at(99, 50), CoverageClass::Visible);
+    assert_eq!(diagram.class_at(29, 1), CoverageClass::Visible);
+
+    let mut csv = Vec::new();
+    diagram.write_csv(&mut csv).unwrap();
+    let text = String::from_utf8(csv).unwrap();
+    assert!(text.starts_with("range_km,height_m,terrain_m,snr_db,class"));
+    assert_eq!(text.lines().count(), 1 + 150 * 61);
+}
+
+#[test]
+fn test_horizon_profile_smooth_earth() {
+    use crate::physics::horizon::compute_horizon_profile;
+
+    let radar = Radar {
+        name: "Horizon".to_string(),
+        location: LatLon { latitude: 45.0, longitude: 5.0, altitude: 20.0 },
+        ..Default::default()
+    };
+    let refraction = RefractionParams { k_factor: 4.0 / 3.0 };
+    let profile = compute_horizon_profile(&radar, &MockTerrain { altitude: 0.0 }, 50_000.0, 7.0, 100.0, refraction);
+
+    // 360 / 7 rounds to 51 azimuths
+    assert_eq!(profile.samples.len(), 51);
+    // Over a smooth sea the horizon is the tangent point: d = sqrt(2 k R h), dip = -sqrt(2 h / (k R))
+    let k_r = crate::geo::EARTH_RADIUS * 4.0 / 3.0;
+    let tangent_m = (2.0 * k_r * 20.0).sqrt();
+    let dip_deg = -(2.0 * 20.0 / k_r).sqrt().to_degrees();
+    for sample in &profile.samples {
+        assert!((sample.elevation_deg - dip_deg).abs() < 1e-3, "{} vs {}", sample.elevation_deg, dip_deg);
+        assert!((sample.distance_m - tangent_m).abs() < 200.0);
+    }
+    assert!((profile.elevation_at(123.4).unwrap() - dip_deg).abs() < 1e-3);
+
+    let mut csv = Vec::new();
+    profile.write_csv(&mut csv).unwrap();
+    assert_eq!(String::from_utf8(csv).unwrap().lines().count(), 52);
+}
+
+#[test]
+fn test_min_altitude_beyond_radio_horizon() {
+    use crate::coverage::min_altitude::{compute_min_altitude_tile, MinAltitudeSearch};
+    use crate::coverage::CoverageRequest;
+    use crate::physics::target::TargetModel;
+    use crate::physics::viewshed::compute_viewshed;
+    use crate::terrain::{TerrainLoader, TerrainManager};
+    use std::sync::Arc;
+
+    // Flat sea-level terrain: the minimum altitude follows the smooth-earth horizon
+    let terrain = Arc::new(TerrainManager::new(TerrainLoader::new("/nonexistent".into()), 4));
+    let radar = Radar {
+        name: "MinAlt".to_string(),
+        location: LatLon { latitude: 45.5, longitude: 5.5, altitude: 20.0 },
+        tx_power_w: 150000.0, gain_dbi: 42.0, frequency_mhz: 3100.0, system_loss_db: 3.0, snr_threshold_db: 13.0,
+        ..Default::default()
+    };
+    let radar_k2 = radar.clone();
+    let viewshed = Arc::new(compute_viewshed(&radar, terrain.as_ref(), 60_000.0, 4.0 / 3.0, None).into());
+    let step = 10;
+    let request = CoverageRequest { target: TargetModel::isotropic("Fighter", 5.0), step_size: step, ..Default::default() };
+    let tile = compute_min_altitude_tile(radar, terrain.clone(), viewshed, 45, 5, &request, &MinAltitudeSearch::default());
+
+    let agl_at = |lat: f64, lon: f64| {
+        let y = (((46.0 - lat) * 1200.0) / step as f64).round() as usize;
+        let x = (((lon - 5.0) * 1200.0) / step as f64).round() as usize;
+        tile.min_altitude_agl(y * tile.size + x)
+    };
+
+    // ~11 km: inside the 18 km horizon of a 20 m antenna, visible down to the surface
+    assert!(agl_at(45.6, 5.5).unwrap() < 1.0);
+    // ~44 km: (44 - 18.4 km)^2 / (2 k R) ~ 39 m
+    let beyond = agl_at(45.9, 5.5).unwrap();
+    assert!((30.0..50.0).contains(&beyond), "min altitude {} m", beyond);
+
+    // The k-factor of the request is used throughout: k = 2 pushes the horizon
+    // to ~22.6 km, (44 - 22.6 km)^2 / (2 k R) ~ 18 m
+    let refraction = RefractionParams { k_factor: 2.0 };
+    let viewshed = Arc::new(compute_viewshed(&radar_k2, terrain.as_ref(), 60_000.0, 2.0, None).into());
+    let request = CoverageRequest { refraction, ..request };
+    let tile = compute_min_altitude_tile(radar_k2, terrain, viewshed, 45, 5, &request, &MinAltitudeSearch::default());
+    let y = (((46.0 - 45.9) * 1200.0) / step as f64).round() as usize;
+    let x = (((5.5 - 5.0) * 1200.0) / step as f64).round() as usize;
+    let beyond_k2 = tile.min_altitude_agl(y * tile.size + x).unwrap();
+    assert!((10.0..28.0).contains(&beyond_k2), "min altitude {} m at k = 2", beyond_k2);
+}
+
+// Rolling hills with ridges a few km apart, enough to cast long shadows
+struct HillyTerrain;
+
+impl TerrainProvider for HillyTerrain {
+    fn get_altitude(&self, loc: LatLon) -> f64 {
+        let a = (loc.latitude * 180.0).sin() * (loc.longitude * 130.0).cos();
+        let b = (loc.latitude * 47.0 + loc.longitude * 61.0).sin();
+        400.0 + 250.0 * a + 150.0 * b
+    }
+}
+
+#[test]
+fn test_viewshed_algorithms_against_los() {
+    use crate::physics::viewshed::{compare_with_los, compute_viewshed_with_algorithm, ViewshedAlgorithm};
+
+    // 30 m mast on the local ground
+    let site = LatLon { latitude: 45.0, longitude: 5.0, altitude: 0.0 };
+    let radar = Radar {
+        name: "Hills".to_string(),
+        location: LatLon { altitude: HillyTerrain.get_altitude(site) + 30.0, ..site },
+        ..Default::default()
+    };
+    let k = 4.0 / 3.0;
+    let los = LosSystem::new(RefractionParams { k_factor: k });
+
+    let mut ratios = Vec::new();
+    for algorithm in ViewshedAlgorithm::ALL {
+        let viewshed = compute_viewshed_with_algorithm(&radar, &HillyTerrain, 15_000.0, 100.0, k as f32, algorithm, None);
+        let error = compare_with_los(&viewshed, &radar, &HillyTerrain, &los, 10.0, 7);
+        assert!(error.samples > 1000, "{:?}", algorithm);
+        // Worst cells sit on ridge crests, where one 100 m cell shifts the horizon by degrees
+        assert!(error.max_abs_error_deg < 5.0, "{:?}: max {:.3}°", algorithm, error.max_abs_error_deg);
+        ratios.push((algorithm, error));
+    }
+
+    let error_of = |algorithm| ratios.iter().find(|(a, _)| *a == algorithm).unwrap().1;
+    let (ray_cast, r3, xdraw) = (error_of(ViewshedAlgorithm::RayCast), error_of(ViewshedAlgorithm::R3), error_of(ViewshedAlgorithm::XDraw));
+    // Error bounds: R3 is the reference, XDraw trades some accuracy for speed.
+    // The residual is mostly the 100 m sampling of LosSystem itself.
+    assert!(r3.disagreement_ratio() < 0.01);
+    assert!(r3.mean_abs_error_deg < 0.1);
+    assert!(r3.mean_abs_error_deg < ray_cast.mean_abs_error_deg);
+    assert!(xdraw.disagreement_ratio() < 0.02);
+    assert!(xdraw.mean_abs_error_deg < 0.25);
+    assert!(ray_cast.disagreement_ratio() < 0.02);
+    assert!(ray_cast.mean_abs_error_deg < 0.3);
+}
+
+#[test]
+fn test_r3_falls_back_to_xdraw_over_budget() {
+    use crate::physics::viewshed::{compute_viewshed_with_algorithm, ViewshedAlgorithm};
+
+    assert!(ViewshedAlgorithm::R3.fits(100_000.0, 100.0));
+    assert!(!ViewshedAlgorithm::R3.fits(470_000.0, 100.0));
+    assert!(ViewshedAlgorithm::XDraw.fits(470_000.0, 100.0));
+
+    // 2020 x 2020 cells, just over the budget
+    let site = LatLon { latitude: 45.0, longitude: 5.0, altitude: 0.0 };
+    let radar = Radar {
+        name: "Hills".to_string(),
+        location: LatLon { altitude: HillyTerrain.get_altitude(site) + 30.0, ..site },
+        ..Default::default()
+    };
+    let r3 = compute_viewshed_with_algorithm(&radar, &HillyTerrain, 101_000.0, 100.0, 4.0 / 3.0, ViewshedAlgorithm::R3, None);
+    let xdraw = compute_viewshed_with_algorithm(&radar, &HillyTerrain, 101_000.0, 100.0, 4.0 / 3.0, ViewshedAlgorithm::XDraw, None);
+    assert_eq!(r3.horizon_map, xdraw.horizon_map);
+}
+
+#[test]
+fn test_parallel_viewshed_is_deterministic() {
+    use crate::physics::viewshed::{compute_viewshed_with_algorithm, ViewshedAlgorithm};
+    use std::sync::Arc;
+    use std::sync::atomic::{AtomicU32, Ordering};
+
+    let site = LatLon { latitude: 45.0, longitude: 5.0, altitude: 0.0 };
+    let radar = Radar {
+        name: "Hills".to_string(),
+        location: LatLon { altitude: HillyTerrain.get_altitude(site) + 30.0, ..site },
+        ..Default::default()
+    };
+
+    for algorithm in ViewshedAlgorithm::ALL {
+        let progress = Arc::new(AtomicU32::new(0));
+        let first = compute_viewshed_with_algorithm(&radar, &HillyTerrain, 8_000.0, 100.0, 4.0 / 3.0, algorithm, Some(progress.clone()));
+        let second = compute_viewshed_with_algorithm(&radar, &HillyTerrain, 8_000.0, 100.0, 4.0 / 3.0, algorithm, None);
+        assert_eq!(first.horizon_map, second.horizon_map, "{:?}", algorithm);
+        // Progress ends exactly at the advertised total
+        assert_eq!(progress.load(Ordering::Relaxed), algorithm.progress_total(first.width, first.height), "{:?}", algorithm);
+    }
+}
+
+#[test]
+fn test_polar_viewshed_matches_cartesian() {
+    use crate::physics::polar_viewshed::{compute_polar_viewshed, PolarViewshed};
+    use crate::physics::viewshed::{compute_viewshed_with_algorithm, ViewshedAlgorithm, ViewshedMonitor};
+
+    let site = LatLon { latitude: 45.0, longitude: 5.0, altitude: 0.0 };
+    let radar = Radar {
+        name: "Hills".to_string(),
+        location: LatLon { altitude: HillyTerrain.get_altitude(site) + 30.0, ..site },
+        ..Default::default()
+    };
+    let k = 4.0 / 3.0;
+    let cartesian = compute_viewshed_with_algorithm(&radar, &HillyTerrain, 15_000.0, 100.0, k as f32, ViewshedAlgorithm::R3, None);
+    let polar = compute_polar_viewshed(&radar, &HillyTerrain, 15_000.0, 100.0, 0.25, k, ViewshedMonitor::default());
+    assert_eq!(polar.n_azimuths, 1440);
+    assert_eq!(polar.n_ranges, 151);
+
+    let (mut samples, mut sum_error) = (0, 0.0);
+    for y in (0..cartesian.height).step_by(7) {
+        for x in (0..cartesian.width).step_by(7) {
+            let loc = cartesian.grid_to_latlon(x, y);
+            let (dist, _) = calculate_geodesic(radar.location, loc);
+            if !(1000.0..=14_500.0).contains(&dist) {
+                continue;
+            }
+            let expected = cartesian.get_horizon_angle(loc).unwrap();
+            let actual = polar.get_horizon_angle(loc).unwrap();
+            sum_error += (actual - expected).abs().to_degrees() as f64;
+            samples += 1;
+        }
+    }
+    let mean_error = sum_error / samples as f64;
+    assert!(samples > 1000);
+    assert!(mean_error < 0.1);
+    assert!(polar.get_horizon_angle(LatLon { latitude: 46.0, ..site }).is_none());
+
+    // Full-range footprint: radar resolution instead of range²
+    let polar_full = PolarViewshed::new(site, 470_000.0, 150.0, 0.1);
+    let cartesian_cells = 9400 * 9400; // 470 km at 100 m
+    assert!(polar_full.horizon_map.len() * 5 < cartesian_cells);
+}
+
+#[test]
+fn test_viewshed_store_round_trip_and_invalidation() {
+    use crate::cache::viewshed::{ViewshedKey, ViewshedStore};
+    use crate::physics::viewshed::{compute_horizon_grid, ViewshedGrid, ViewshedSettings};
+
+    let site = LatLon { latitude: 45.0, longitude: 5.0, altitude: 0.0 };
+    let radar = Radar {
+        name: "Hills".to_string(),
+        location: LatLon { altitude: HillyTerrain.get_altitude(site) + 30.0, ..site },
+        ..Default::default()
+    };
+    let store = ViewshedStore::new(std::env::temp_dir().join(format!("rcvs_test_{}", std::process::id())));
+
+    let cartesian = ViewshedSettings { range_m: 5_000.0, ..Default::default() };
+    let polar = ViewshedSettings { grid: ViewshedGrid::Polar { range_bin_m: 100.0, azimuth_bin_deg: 1.0 }, ..cartesian };
+    for settings in [cartesian, polar] {
+        let key = ViewshedKey::new(&radar, 4.0 / 3.0, &settings, 42);
+        assert!(store.load(&key).is_none());
+
+        let grid = compute_horizon_grid(&radar, &HillyTerrain, &settings, 4.0 / 3.0, None, None);
+        store.save(&key, &grid).unwrap();
+        assert_eq!(store.load(&key), Some(grid));
+    }
+
+    // Any change of k, terrain, geometry or algorithm parameters is a different key
+    let key = ViewshedKey::new(&radar, 4.0 / 3.0, &cartesian, 42);
+    let raised = Radar { antenna_height_agl: radar.antenna_height_agl + 10.0, ..radar.clone() };
+    let finer = ViewshedSettings { cell_size_m: 50.0, ..cartesian };
+    // Below the k resolution, e.g. while the slider is dragged, the key holds
+    assert_eq!(ViewshedKey::new(&radar, 4.0 / 3.0 + 0.001, &cartesian, 42).digest(), key.digest());
+    for other in [
+        ViewshedKey::new(&radar, 1.2, &cartesian, 42),
+        ViewshedKey::new(&radar, 4.0 / 3.0, &cartesian, 43),
+        ViewshedKey::new(&raised, 4.0 / 3.0, &cartesian, 42),
+        ViewshedKey::new(&radar, 4.0 / 3.0, &finer, 42),
+    ] {
+        assert_ne!(other.digest(), key.digest());
+        assert!(store.load(&other).is_none());
+    }
+    std::fs::remove_dir_all(&store.dir).unwrap();
+}
+
+#[test]
+fn test_viewshed_store_evicts_least_recently_used() {
+    use crate::cache::viewshed::{ViewshedKey, ViewshedStore};
+    use crate::physics::viewshed::{compute_horizon_grid, ViewshedSettings};
+
+    let site = LatLon { latitude: 45.0, longitude: 5.0, altitude: 0.0 };
+    let radar = Radar {
+        name: "Hills".to_string(),
+        location: LatLon { altitude: HillyTerrain.get_altitude(site) + 30.0, ..site },
+        ..Default::default()
+    };
+    let settings = ViewshedSettings { range_m: 3_000.0, ..Default::default() };
+    let grid = compute_horizon_grid(&radar, &HillyTerrain, &settings, 4.0 / 3.0, None, None);
+    let keys: Vec<_> = (0..3).map(|i| ViewshedKey::new(&radar, 4.0 / 3.0, &settings, i)).collect();
+
+    // Room for two files
+    let mut store = ViewshedStore::new(std::env::temp_dir().join(format!("rcvs_lru_test_{}", std::process::id())));
+    store.save(&keys[0], &grid).unwrap();
+    store.max_bytes = 2 * std::fs::metadata(store.path(&keys[0])).unwrap().len();
+    let pause = || std::thread::sleep(std::time::Duration::from_millis(20));
+    pause();
+    store.save(&keys[1], &grid).unwrap();
+    pause();
+    // Using the first file makes the second one the least recently used
+    assert!(store.load(&keys[0]).is_some());
+    pause();
+    store.save(&keys[2], &grid).unwrap();
+
+    assert!(store.load(&keys[0]).is_some());
+    assert!(store.load(&keys[1]).is_none());
+    assert!(store.load(&keys[2]).is_some());
+    std::fs::remove_dir_all(&store.dir).unwrap();
+}
+
+#[test]
+fn test_viewshed_store_rejects_corrupt_files() {
+    use std::io::Write;
+    use crate::cache::viewshed::{read_grid, write_grid, VIEWSHED_FORMAT_VERSION};
+    use crate::physics::viewshed::{HorizonGrid, Viewshed};
+
+    let grid = HorizonGrid::Cartesian(Viewshed::new(LatLon { latitude: 45.0, longitude: 5.0, altitude: 0.0 }, 1_000.0, 100.0));
+    let mut bytes = Vec::new();
+    write_grid(&mut bytes, 7, &grid).unwrap();
+    assert_eq!(read_grid(bytes.as_slice(), 7).unwrap(), Some(grid));
+
+    // Interrupted write
+    for len in [10, 20, bytes.len() - 8] {
+        assert!(read_grid(&bytes[..len], 7).is_err(), "truncated to {}", len);
+    }
+
+    // Header of a Cartesian grid with the given geometry and dimensions, no data
+    let forged = |radius_m: f64, cell_size_m: f64, n: u32, m: u32| {
+        let mut bytes = b"RCVS".to_vec();
+        bytes.extend(VIEWSHED_FORMAT_VERSION.to_le_bytes());
+        bytes.extend(7u64.to_le_bytes());
+        let mut gz = flate2::write::GzEncoder::new(bytes, flate2::Compression::default());
+        gz.write_all(&[0]).unwrap();
+        for value in [45.0, 5.0, 0.0, radius_m, cell_size_m] {
+            gz.write_all(&f64::to_le_bytes(value)).unwrap();
+        }
+        gz.write_all(&n.to_le_bytes()).unwrap();
+        gz.write_all(&m.to_le_bytes()).unwrap();
+        gz.finish().unwrap()
+    };
+    // Dimensions that disagree with the geometry, or too large to allocate
+    assert!(read_grid(forged(1_000.0, 100.0, u32::MAX, u32::MAX).as_slice(), 7).is_err());
+    assert!(read_grid(forged(1e9, 1.0, 2_000_000_000, 2_000_000_000).as_slice(), 7).is_err());
+    // Consistent and small, but the data is missing
+    assert!(read_grid(forged(1_000.0, 100.0, 20, 20).as_slice(), 7).is_err());
+}
+
+#[test]
+fn test_job_scheduler_priority_dedup_and_cancel() {
+    use crate::jobs::{JobPriority, JobScheduler};
+
+    // One job at a time, so results come back in start order
+    let mut jobs: JobScheduler<u32, u32> = JobScheduler::new(1);
+    let near = JobPriority { visible: false, distance: 10.0 };
+    let far = JobPriority { visible: false, distance: 1000.0 };
+    let on_screen = JobPriority { visible: true, distance: 5000.0 };
+    assert!(jobs.submit(1, JobPriority::BACKGROUND, |_| Some(1)));
+    assert!(jobs.submit(2, far, |_| Some(2)));
+    assert!(jobs.submit(3, on_screen, |_| Some(3)));
+    assert!(jobs.submit(4, near, |_| Some(4)));
+    // Same key again is deduplicated
+    assert!(!jobs.submit(4, on_screen, |_| Some(40)));
+
+    let order: Vec<u32> = jobs.wait_all().into_iter().map(|(key, _)| key).collect();
+    assert_eq!(order, vec![3, 4, 2, 1]);
+
+    // A running job sees its token raised and its result is dropped
+    jobs.submit(5, near, |ctx| {
+        let start = std::time::Instant::now();
+        while !ctx.cancel.is_cancelled() && start.elapsed().as_secs() < 10 {
+            std::thread::yield_now();
+        }
+        Some(5)
+    });
+    jobs.submit(6, far, |_| Some(6));
+    assert!(jobs.poll().is_empty());
+    jobs.cancel(&5);
+    // The cancelled job holds the only slot until it returns
+    let stats = jobs.stats();
+    assert_eq!((stats.queued, stats.running, stats.cancelling), (1, 0, 1));
+    jobs.cancel(&6);
+    assert!(jobs.wait_all().is_empty());
+    let stats = jobs.stats();
+    assert_eq!((stats.completed, stats.cancelled, stats.cancelling), (4, 2, 0));
+}
+
+#[test]
+fn test_job_scheduler_survives_panicking_job() {
+    use crate::jobs::{JobPriority, JobScheduler};
+
+    let mut jobs: JobScheduler<u32, u32> = JobScheduler::new(1);
+    jobs.submit(1, JobPriority::BACKGROUND, |_| panic!("job failure"));
+    jobs.submit(2, JobPriority::BACKGROUND, |_| Some(2));
+    // The panicking job frees its slot and the next one still runs
+    assert_eq!(jobs.wait_all(), vec![(2, 2)]);
+    assert!(jobs.is_idle());
+    assert_eq!(jobs.stats().completed, 1);
+}
+
+#[test]
+fn test_merge_orbit_tiles_keeps_best_class() {
+    use crate::coverage::{merge_coverage_tiles, CoverageClass, CoverageTile};
+
+    // Two orbit positions: each sees what the other misses
+    let mut a = CoverageTile::from_classes(45, 5, 2, &[CoverageClass::Visible, CoverageClass::Shadowed, CoverageClass::OutOfRange, CoverageClass::Shadowed]);
+    let mut b = CoverageTile::from_classes(45, 5, 2, &[CoverageClass::Shadowed, CoverageClass::Visible, CoverageClass::Shadowed, CoverageClass::Shadowed]);
+    a.clearance_deg = vec![1.0, -2.0, f32::NAN, -3.0];
+    b.clearance_deg = vec![-1.0, 0.5, -4.0, -1.5];
+
+    let merged = merge_coverage_tiles(&[a, b]).unwrap();
+    assert!(merged.data.iter().take(2).all(|&c| c == CoverageClass::Visible as u8));
+    assert_eq!(merged.data[2], CoverageClass::Shadowed as u8);
+    assert_eq!(merged.data[3], CoverageClass::Shadowed as u8);
+    // Margins follow the winning class, or the best of equal classes
+    assert_eq!(merged.clearance_deg, vec![1.0, 0.5, -4.0, -1.5]);
+    assert!(merge_coverage_tiles(&[]).is_none());
+}
+
+#[test]
+fn test_composite_counts_and_best_radar() {
+    use crate::coverage::composite::{composite_coverage_tiles, CompositeMode, NO_RADAR};
+    use crate::coverage::{CoverageClass, CoverageTile};
+
+    let tile = |data: [CoverageClass; 4], pd: [f32; 4]| CoverageTile { pd: pd.to_vec(), ..CoverageTile::from_classes(45, 5, 2, &data) };
+    use CoverageClass::*;
+    let a = tile([Visible, Visible, Shadowed, OutOfRange], [0.6, 0.9, 0.0, 0.0]);
+    let b = tile([Visible, Shadowed, Jammed, OutOfRange], [0.8, 0.0, 0.0, 0.0]);
+    let composite = composite_coverage_tiles(&[&a, &b]).unwrap();
+
+    assert_eq!(composite.radar_count, vec![2, 1, 0, 0]);
+    // Highest Pd among detecting radars, then best class where nobody detects
+    assert_eq!(composite.best_radar, vec![1, 0, 1, NO_RADAR]);
+    assert_eq!(composite.best_pd, vec![0.8, 0.9, 0.0, 0.0]);
+
+    let covered = |mode| (0..4).map(|i| composite.covered(i, mode)).collect::<Vec<_>>();
+    assert_eq!(covered(CompositeMode::Any), vec![true, true, false, false]);
+    assert_eq!(covered(CompositeMode::AtLeast(2)), vec![true, false, false, false]);
+}
+
+#[test]
+fn test_redundancy_and_n_minus_one() {
+    use crate::coverage::composite::{composite_coverage_tiles, CompositeMode};
+    use crate::coverage::redundancy::RedundancySummary;
+    use crate::coverage::{tile_cell_area_km2, CoverageClass, CoverageTile};
+
+    // A tile sums to its 1° square on the WGS84 ellipsoid (8686.5 km² at 45°N)
+    let expected = crate::geo::ellipsoid_area_km2(45.0, 46.0, 5.0, 6.0);
+    assert!((expected - 8686.49).abs() < 0.01, "{}", expected);
+    let total: f64 = (0..601 * 601).map(|idx| tile_cell_area_km2(45, 601, idx)).sum();
+    assert!((total - expected).abs() / expected < 1e-9, "{} vs {}", total, expected);
+
+    // 2 × 2 tile plus the shared edge row and column, which count zero
+    let tile = |detected: [bool; 4]| {
+        let mut data = [CoverageClass::Visible; 9];
+        for (cell, detected) in [0, 1, 3, 4].into_iter().zip(detected) {
+            data[cell] = if detected { CoverageClass::Visible } else { CoverageClass::Shadowed };
+        }
+        CoverageTile::from_classes(45, 5, 3, &data)
+    };
+    let a = tile([true, true, true, false]);
+    let b = tile([false, true, true, false]);
+    let c = tile([false, false, true, false]);
+    let composite = composite_coverage_tiles(&[&a, &b, &c]).unwrap();
+    let summary = RedundancySummary::of_tile(&composite, 3);
+
+    let cell = |idx| tile_cell_area_km2(45, 3, idx);
+    assert!((summary.single_km2 - cell(0)).abs() < 1e-9);
+    assert!((summary.double_km2 - cell(1)).abs() < 1e-9);
+    assert!((summary.triple_plus_km2 - cell(3)).abs() < 1e-9);
+    // Only radar A covers cell 0: it is the single point of failure there
+    assert!((summary.sole_km2[0] - cell(0)).abs() < 1e-9);
+    assert_eq!(&summary.sole_km2[1..], &[0.0, 0.0]);
+    assert!(!composite.covered(0, CompositeMode::WithoutRadar(0)));
+    assert!(composite.covered(1, CompositeMode::WithoutRadar(0)));
+
+    let mut total = RedundancySummary::default();
+    total.add(&summary);
+    total.add(&summary);
+    assert!((total.loss_percent(0) - summary.loss_percent(0)).abs() < 1e-9);
+}
+
+#[test]
+fn test_coverage_volume_layers_and_sampling() {
+    use crate::coverage::volume::{compute_coverage_volume, CoverageVolumeParams};
+    use crate::coverage::{compute_coverage_tile, AltitudeReference, CoverageRequest};
+    use crate::physics::viewshed::Viewshed;
+    use crate::terrain::{TerrainLoader, TerrainManager};
+    use std::sync::Arc;
+
+    let terrain = Arc::new(TerrainManager::new(TerrainLoader::new("/nonexistent".into()), 4));
+    let radar = Radar {
+        name: "Volume".to_string(),
+        location: LatLon { latitude: 45.5, longitude: 5.5, altitude: 20.0 },
+        tx_power_w: 150000.0, gain_dbi: 42.0, frequency_mhz: 3100.0, system_loss_db: 3.0, snr_threshold_db: 13.0,
+        max_elevation_deg: Some(10.0),
+        ..Default::default()
+    };
+    let viewshed: Arc<crate::physics::viewshed::HorizonGrid> = Arc::new(Viewshed::new(radar.location, 120_000.0, 1000.0).into());
+    let request = CoverageRequest { step_size: 40, ..Default::default() };
+    let params = CoverageVolumeParams::evenly_spaced(AltitudeReference::Agl, 1_000.0, 3_000.0, 3);
+    let volume = compute_coverage_volume(radar.clone(), terrain.clone(), viewshed.clone(), (45, 5), &request, &params, &JobContext::default()).unwrap();
+
+    // Each layer is the flat tile at that altitude
+    for (layer, &altitude) in volume.layers.iter().zip(&volume.altitudes_m) {
+        let flat = CoverageRequest { target_altitude: altitude, ..request.clone() };
+        let tile = compute_coverage_tile(radar.clone(), terrain.clone(), viewshed.clone(), 45, 5, &flat, &JobContext::default()).unwrap();
+        assert_eq!(layer.data, tile.data);
+    }
+
+    // Halfway between two layers the margin is interpolated, outside the stack there is nothing
+    let (lat, lon) = (45.8, 5.5);
+    let idx = volume.cell_index(lat, lon).unwrap();
+    let mid = volume.sample(lat, lon, 1_500.0).unwrap();
+    let expected = (volume.layers[0].snr_margin_db[idx] + volume.layers[1].snr_margin_db[idx]) / 2.0;
+    assert!((mid.snr_margin_db - expected).abs() < 1e-4);
+    assert!(volume.sample(lat, lon, 5_000.0).is_none());
+    assert!(volume.sample(47.0, lon, 2_000.0).is_none());
+}
+
+#[test]
+fn test_altitude_reference_and_flight_levels() {
+    use crate::coverage::{compute_coverage_tile, flight_level_to_m, pressure_altitude_to_amsl, AltitudeReference, CoverageClass, CoverageRequest, STANDARD_PRESSURE_HPA};
+    use crate::physics::viewshed::Viewshed;
+    use crate::terrain::{TerrainLoader, TerrainManager};
+    use std::sync::Arc;
+
+    // FL100 is 3048 m on the standard setting, about 8.3 m higher per hPa of QNH
+    let fl100 = flight_level_to_m(100.0);
+    assert!((fl100 - 3048.0).abs() < 1e-9);
+    assert!((pressure_altitude_to_amsl(fl100, STANDARD_PRESSURE_HPA) - fl100).abs() < 1e-6);
+    let high = pressure_altitude_to_amsl(fl100, STANDARD_PRESSURE_HPA + 10.0);
+    assert!((high - fl100 - 85.0).abs() < 10.0, "{}", high - fl100);
+    assert!(pressure_altitude_to_amsl(fl100, STANDARD_PRESSURE_HPA - 10.0) < fl100);
+
+    // No terrain data: ground is at sea level, so all three references agree
+    let terrain = Arc::new(TerrainManager::new(TerrainLoader::new("/nonexistent".into()), 4));
+    let radar = Radar {
+        name: "Reference".to_string(),
+        location: LatLon { latitude: 45.5, longitude: 5.5, altitude: 20.0 },
+        tx_power_w: 150000.0, gain_dbi: 42.0, frequency_mhz: 3100.0, system_loss_db: 3.0, snr_threshold_db: 13.0,
+        ..Default::default()
+    };
+    let viewshed: Arc<crate::physics::viewshed::HorizonGrid> = Arc::new(Viewshed::new(radar.location, 120_000.0, 1000.0).into());
+    let tile = |target_altitude: f64, altitude_reference: AltitudeReference| {
+        let request = CoverageRequest { target_altitude, altitude_reference, step_size: 40, ..Default::default() };
+        compute_coverage_tile(radar.clone(), terrain.clone(), viewshed.clone(), 45, 5, &request, &JobContext::default()).unwrap()
+    };
+    let agl = tile(fl100, AltitudeReference::Agl);
+    assert_eq!(agl.data, tile(fl100, AltitudeReference::Amsl).data);
+    assert_eq!(agl.data, tile(fl100, AltitudeReference::FlightLevel).data);
+
+    // An AMSL altitude under the ground is shadowed wherever the radar reaches
+    let below = tile(-10.0, AltitudeReference::Amsl);
+    let reached: Vec<_> = below.data.iter().filter(|&&c| c != CoverageClass::OutOfRange as u8).collect();
+    assert!(!reached.is_empty());
+    assert!(reached.iter().all(|&&c| c == CoverageClass::Shadowed as u8));
+}
+
+#[test]
+fn test_coverage_statistics_area_and_sector_range() {
+    use crate::coverage::statistics::{CoverageStatistics, RadarStatistics, StatisticsReport};
+    use crate::coverage::{tile_cell_area_km2, CoverageClass, CoverageTile};
+    use crate::physics::los::calculate_geodesic;
+
+    // 2 × 2 tile (plus the zero-area shared edges) with the radar at its south-west corner
+    let tile = |classes: [CoverageClass; 4]| {
+        let mut data = [CoverageClass::OutOfRange; 9];
+        for (cell, class) in [0, 1, 3, 4].into_iter().zip(classes) {
+            data[cell] = class;
+        }
+        CoverageTile::from_classes(45, 5, 3, &data)
+    };
+    use CoverageClass::*;
+    let a = tile([Visible, Shadowed, RangeAmbiguous, OutOfRange]);
+    let b = tile([Shadowed, Visible, Shadowed, OutOfRange]);
+    let origin = LatLon { latitude: 45.0, longitude: 5.0, altitude: 0.0 };
+    let cell = |idx| tile_cell_area_km2(45, 3, idx);
+
+    let stats = CoverageStatistics::of_tile(&a, origin, 4);
+    assert!((stats.region_km2 - crate::geo::ellipsoid_area_km2(45.0, 46.0, 5.0, 6.0)).abs() < 1e-6);
+    assert!((stats.covered_km2 - cell(0) - cell(3)).abs() < 1e-9);
+    assert!((stats.shadowed_km2 - cell(1)).abs() < 1e-9);
+    // Detections at (46, 5) due north and (45.5, 5) also north: farthest is the corner
+    let (north, _) = calculate_geodesic(origin, LatLon { latitude: 46.0, longitude: 5.0, altitude: 0.0 });
+    assert!((stats.sector_range_m[0] - north).abs() < 1e-6);
+    assert_eq!(&stats.sector_range_m[1..], &[0.0, 0.0, 0.0]);
+    assert_eq!(stats.regroup(2).sector_range_m, vec![north, 0.0]);
+
+    // Summing tiles keeps the farthest range and adds areas
+    let mut total = stats.clone();
+    total.add(&CoverageStatistics::of_tile(&b, origin, 4));
+    assert_eq!(total.tiles, 2);
+    assert!((total.covered_percent() - (cell(0) + cell(3) + cell(1)) / (2.0 * stats.region_km2) * 100.0).abs() < 1e-9);
+
+    // Network: covered where any radar detects, shadowed where none does and one is masked
+    let network = CoverageStatistics::of_network(&[&a, &b]);
+    assert!((network.covered_km2 - cell(0) - cell(1) - cell(3)).abs() < 1e-9);
+    assert!(network.shadowed_km2.abs() < 1e-9);
+    assert!(network.sector_range_m.is_empty());
+
+    let report = StatisticsReport { radars: vec![RadarStatistics { name: "A".to_string(), statistics: stats }], network };
+    let mut csv = Vec::new();
+    report.write_csv(&mut csv).unwrap();
+    let csv = String::from_utf8(csv).unwrap();
+    assert!(csv.starts_with("scope,metric,value\n"));
+    assert!(csv.contains(&format!("A,range_km 0-90,{:.1}\n", north / 1000.0)));
+    assert!(csv.contains("network,covered_percent,"));
+}
+
+// Flat ground with a 3000 m ring wall 2-3 km around `center`
+struct RingWall {
+    center: LatLon,
+}
+
+impl TerrainProvider for RingWall {
+    fn get_altitude(&self, loc: LatLon) -> f64 {
+        let (dist, _) = calculate_geodesic(self.center, loc);
+        if (2_000.0..=3_000.0).contains(&dist) { 3_000.0 } else { 0.0 }
+    }
+}
+
+#[test]
+fn test_bistatic_coverage_needs_both_legs() {
+    use crate::coverage::{compute_bistatic_coverage_tile, CoverageClass, CoverageRequest};
+    use crate::physics::bistatic::MultistaticNetwork;
+    use crate::physics::viewshed::{compute_viewshed_with_algorithm, HorizonGrid, ViewshedAlgorithm};
+    use crate::terrain::{TerrainLoader, TerrainManager};
+    use std::sync::Arc;
+
+    let site = |name: &str, longitude: f64| Radar {
+        name: name.to_string(),
+        location: LatLon { latitude: 45.5, longitude, altitude: 20.0 },
+        tx_power_w: 150000.0, gain_dbi: 42.0, frequency_mhz: 3100.0, system_loss_db: 3.0, snr_threshold_db: 13.0,
+        ..Default::default()
+    };
+    let (tx, rx) = (site("Tx", 5.3), site("Rx", 5.7));
+    let network = MultistaticNetwork { name: "Pair".to_string(), transmitter: tx.clone(), receivers: vec![rx.clone()] };
+    let terrain = Arc::new(TerrainManager::new(TerrainLoader::new("/nonexistent".into()), 4));
+    let k = 4.0 / 3.0;
+    let viewshed = |radar: &Radar, ground: &(dyn TerrainProvider + Sync)| -> Arc<HorizonGrid> {
+        Arc::new(compute_viewshed_with_algorithm(radar, ground, 60_000.0, 200.0, k, ViewshedAlgorithm::RayCast, None).into())
+    };
+    let flat = MockTerrain { altitude: 0.0 };
+    let tx_viewshed = viewshed(&tx, &flat);
+    let request = CoverageRequest { target_altitude: 500.0, step_size: 40, ..Default::default() };
+
+    let open = compute_bistatic_coverage_tile(&network, terrain.clone(), tx_viewshed.clone(), &[viewshed(&rx, &flat)], 45, 5, &request);
+    let walled = compute_bistatic_coverage_tile(&network, terrain, tx_viewshed, &[viewshed(&rx, &RingWall { center: rx.location })], 45, 5, &request);
+
+    // The transmitter leg is clear everywhere: only the receiver's wall changes the result
+    let visible = open.data.iter().filter(|&&c| c == CoverageClass::Visible as u8).count();
+    assert!(visible > open.data.len() / 2);
+    let mut masked = 0;
+    for (before, after) in open.data.iter().zip(&walled.data) {
+        if *before == CoverageClass::Visible as u8 && *after != CoverageClass::Visible as u8 {
+            assert_eq!(*after, CoverageClass::Shadowed as u8);
+            masked += 1;
+        }
+    }
+    assert!(masked > visible / 2, "{} of {} masked", masked, visible);
+}
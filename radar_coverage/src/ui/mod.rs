@@ -1,39 +1,40 @@
 use bevy::prelude::*;
+use bevy::ecs::system::SystemParam;
 use bevy::input::mouse::{MouseMotion, MouseWheel};
 use bevy_egui::{egui, EguiContexts};
 use crate::geo::LatLon;
 use crate::physics::refraction::RefractionParams;
-use std::sync::atomic::Ordering;
+use crate::physics::detection::{DetectionParams, SwerlingModel};
+use crate::physics::target::{AspectMode, TargetLibrary, TargetModel};
+use crate::physics::viewshed::ViewshedGrid;
+use crate::coverage::composite::CompositeMode;
+use crate::coverage::AltitudeReference;
+use crate::render::CoverageColoring;
 
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
-pub enum RCSProfile {
-    StealthFighter, // 5G (0.1)
-    Fighter,        // 4G (5.0)
-    SmallAircraft,  // 2.0
-    LargeAircraft,  // 50.0
-    Ship,           // 5000.0
+/// Which product the coverage overlay shows
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub enum CoverageLayer {
+    #[default]
+    Detection, // Where the radars detect the target
+    Intercept, // Where an ESM receiver intercepts the radars
+    MinAltitude, // Lowest altitude at which the target is detected, independent of target AGL
+    Bistatic, // One radar transmits, the selected receivers detect
 }
 
-impl RCSProfile {
-    pub fn value(&self) -> f64 {
-        match self {
-            RCSProfile::StealthFighter => 0.1,
-            RCSProfile::Fighter => 5.0,
-            RCSProfile::SmallAircraft => 2.0,
-            RCSProfile::LargeAircraft => 50.0,
-            RCSProfile::Ship => 5000.0,
-        }
-    }
-    
+impl CoverageLayer {
     pub fn label(&self) -> &'static str {
         match self {
-            RCSProfile::StealthFighter => "5G Stealth Fighter (0.1 m²)",
-            RCSProfile::Fighter => "4G Fighter (5.0 m²)",
-            RCSProfile::SmallAircraft => "Small Aircraft (2.0 m²)",
-            RCSProfile::LargeAircraft => "Large Aircraft (50.0 m²)",
-            RCSProfile::Ship => "Ship (5000.0 m²)",
+            CoverageLayer::Detection => "Radar Detection",
+            CoverageLayer::Intercept => "ESM Intercept",
+            CoverageLayer::MinAltitude => "Minimum Visible Altitude",
+            CoverageLayer::Bistatic => "Bistatic / Multistatic",
         }
     }
+
+    /// Visible cells are target detections, as the coverage statistics count them
+    pub fn detects_target(&self) -> bool {
+        matches!(self, CoverageLayer::Detection | CoverageLayer::Bistatic)
+    }
 }
 
 #[derive(Resource)]
@@ -42,8 +43,19 @@ pub struct MapController {
     pub zoom: f32, // Logarithmic zoom level? Or simple scale. Let's use scale (pixels per meter).
     pub move_speed: f32,
     pub show_coverage: bool,
-    pub target_agl: f32,
-    pub rcs_profile: RCSProfile,
+    pub target_altitude: f32, // Metres above `altitude_reference` (pressure altitude for flight levels)
+    pub altitude_reference: AltitudeReference,
+    pub qnh_hpa: f32, // Sea-level pressure for flight levels
+    pub target_index: usize, // Index into TargetLibrary::targets
+    pub aspect: AspectMode,
+    pub clutter_enabled: bool,
+    pub jamming_enabled: bool,
+    pub layer: CoverageLayer,
+    pub min_altitude_agl: bool, // Show the minimum altitude above ground instead of AMSL
+    pub composite: Option<CompositeMode>, // Fuse the radars into one network layer, None draws one layer per radar
+    pub coloring: CoverageColoring, // What the per-radar detection / intercept overlays show
+    pub bistatic_transmitter: Option<String>, // Radar name, owner of the bistatic layer
+    pub bistatic_receivers: Vec<String>,      // Radar names listening to the transmitter
 }
 
 impl Default for MapController {
@@ -53,12 +65,33 @@ impl Default for MapController {
             zoom: 100.0, // Matches 2000m altitude (200000 / 2000)
             move_speed: 1000.0,
             show_coverage: false,
-            target_agl: 50.0,
-            rcs_profile: RCSProfile::Fighter,
+            target_altitude: 50.0,
+            altitude_reference: AltitudeReference::Agl,
+            qnh_hpa: crate::coverage::STANDARD_PRESSURE_HPA as f32,
+            target_index: 1, // 4G Fighter
+            aspect: AspectMode::default(),
+            clutter_enabled: false,
+            jamming_enabled: false,
+            layer: CoverageLayer::Detection,
+            min_altitude_agl: true,
+            composite: None,
+            coloring: CoverageColoring::Class,
+            bistatic_transmitter: None,
+            bistatic_receivers: Vec::new(),
         }
     }
 }
 
+impl MapController {
+    /// The composite mode if the current layer is fused. Only detections are: the
+    /// minimum altitude layer already takes the lowest altitude of all radars, the
+    /// intercept layer is what an opponent's ESM sees of each radar, and the
+    /// bistatic layer has a single owner
+    pub fn active_composite(&self) -> Option<CompositeMode> {
+        self.composite.filter(|_| self.layer == CoverageLayer::Detection)
+    }
+}
+
 use bevy::window::PrimaryWindow;
 
 pub fn map_control_system(
@@ -189,13 +222,30 @@ pub fn map_control_system(
     // controller.center = ... (requires keeping track of lat/lon ref)
 }
 
+/// Analysis windows opened from the main panel
+#[derive(SystemParam)]
+pub struct AnalysisWindows<'w> {
+    pub vertical: ResMut<'w, VerticalCoverageView>,
+    pub horizon: ResMut<'w, HorizonProfileView>,
+    pub redundancy: ResMut<'w, RedundancyView>,
+    pub volume: ResMut<'w, CoverageVolumeView>,
+    pub statistics: ResMut<'w, CoverageStatisticsView>,
+}
+
 pub fn ui_panel_system(
     mut contexts: EguiContexts,
     mut refraction: ResMut<RefractionParams>,
-    radars: Query<&crate::io::Radar>,
+    mut clutter: ResMut<crate::physics::clutter::ClutterModel>,
+    mut esm_receiver: ResMut<crate::physics::esm::EsmReceiver>,
+    mut radars: Query<&mut crate::io::Radar>,
+    jammers: Query<&crate::io::Jammer>,
     mut controller: ResMut<MapController>,
     metrics: Res<crate::cache::CoverageMetrics>,
-    computing_radars: Query<(&crate::io::Radar, &crate::physics::viewshed::ViewshedProgress)>,
+    computing_radars: Query<(Entity, &crate::jobs::JobProgress)>,
+    jobs: Res<crate::jobs::JobsOverview>,
+    targets: Res<TargetLibrary>,
+    mut windows: AnalysisWindows,
+    mut viewshed_settings: ResMut<crate::physics::viewshed::ViewshedSettings>,
 ) {
     let ctx = match contexts.try_ctx_mut() {
         Some(ctx) => ctx,
@@ -206,60 +256,1040 @@ pub fn ui_panel_system(
         
         // Explicit dereference for ResMut
         ui.add(egui::Slider::new(&mut refraction.k_factor, 1.0..=2.0).text("K-Factor"));
+
+        let mut algorithm = viewshed_settings.algorithm;
+        egui::ComboBox::from_label("Viewshed Algorithm")
+            .selected_text(algorithm.label())
+            .show_ui(ui, |ui| {
+                // R3 is only offered when the grid fits its budget
+                for candidate in crate::physics::viewshed::ViewshedAlgorithm::ALL {
+                    let fits = candidate.fits(viewshed_settings.range_m, viewshed_settings.cell_size_m);
+                    ui.add_enabled_ui(fits, |ui| {
+                        ui.selectable_value(&mut algorithm, candidate, candidate.label())
+                            .on_disabled_hover_text("Grid too large for R3, XDraw is used instead");
+                    });
+                }
+            });
+        if algorithm != viewshed_settings.algorithm {
+            viewshed_settings.algorithm = algorithm;
+        }
+
+        // Changing the grid recomputes the viewsheds
+        let mut grid = viewshed_settings.grid;
+        let polar = match grid {
+            ViewshedGrid::Polar { .. } => grid,
+            ViewshedGrid::Cartesian => ViewshedGrid::Polar { range_bin_m: 150.0, azimuth_bin_deg: 0.1 },
+        };
+        egui::ComboBox::from_label("Viewshed Grid")
+            .selected_text(grid.label())
+            .show_ui(ui, |ui| {
+                ui.selectable_value(&mut grid, ViewshedGrid::Cartesian, ViewshedGrid::Cartesian.label());
+                ui.selectable_value(&mut grid, polar, polar.label());
+            });
+        if let ViewshedGrid::Polar { range_bin_m, azimuth_bin_deg } = &mut grid {
+            ui.add(egui::DragValue::new(range_bin_m).range(10.0..=5000.0).speed(10.0).prefix("Range bin: ").suffix(" m"));
+            ui.add(egui::DragValue::new(azimuth_bin_deg).range(0.01..=5.0).speed(0.01).prefix("Azimuth bin: ").suffix("°"));
+        }
+        if grid != viewshed_settings.grid {
+            viewshed_settings.grid = grid;
+        }
         ui.checkbox(&mut controller.show_coverage, "Show Coverage");
         if controller.show_coverage {
-            ui.horizontal(|ui| {
-                if ui.button("<").clicked() {
-                    controller.target_agl = (controller.target_agl - 50.0).max(10.0);
+            egui::ComboBox::from_label("Coverage Layer")
+                .selected_text(controller.layer.label())
+                .show_ui(ui, |ui| {
+                    ui.selectable_value(&mut controller.layer, CoverageLayer::Detection, CoverageLayer::Detection.label());
+                    ui.selectable_value(&mut controller.layer, CoverageLayer::Intercept, CoverageLayer::Intercept.label());
+                    ui.selectable_value(&mut controller.layer, CoverageLayer::MinAltitude, CoverageLayer::MinAltitude.label());
+                    ui.selectable_value(&mut controller.layer, CoverageLayer::Bistatic, CoverageLayer::Bistatic.label());
+                });
+            let mut names: Vec<String> = radars.iter().map(|r| r.name.clone()).collect();
+            names.sort();
+            if controller.layer == CoverageLayer::Bistatic {
+                bistatic_ui(ui, &mut controller, &names);
+            }
+            if controller.layer != CoverageLayer::MinAltitude {
+                if controller.layer == CoverageLayer::Detection {
+                    composite_ui(ui, &mut controller.composite, &names);
                 }
-                ui.add(egui::Slider::new(&mut controller.target_agl, 10.0..=2000.0).text("Target AGL (m)"));
-                if ui.button(">").clicked() {
-                    controller.target_agl = (controller.target_agl + 50.0).min(2000.0);
+                if controller.active_composite().is_none() {
+                    // Display only: re-colours the computed tiles
+                    egui::ComboBox::from_label("Colour By")
+                        .selected_text(controller.coloring.label())
+                        .show_ui(ui, |ui| {
+                            for coloring in [CoverageColoring::Class, CoverageColoring::Clearance, CoverageColoring::SnrMargin, CoverageColoring::Pd] {
+                                ui.selectable_value(&mut controller.coloring, coloring, coloring.label());
+                            }
+                        });
                 }
-            });
+            }
+            if controller.layer == CoverageLayer::MinAltitude {
+                // Display only: switching reference re-colours the cached tiles
+                ui.horizontal(|ui| {
+                    ui.radio_value(&mut controller.min_altitude_agl, true, "AGL");
+                    ui.radio_value(&mut controller.min_altitude_agl, false, "AMSL");
+                });
+            }
+
+            target_altitude_ui(ui, &mut controller);
             
             ui.add_space(5.0);
+
+            if controller.layer == CoverageLayer::Intercept {
+                // The altitude slider above sets the ESM receiver altitude
+                let mut receiver = *esm_receiver;
+                ui.add(egui::Slider::new(&mut receiver.sensitivity_dbm, -100.0..=-30.0).text("ESM Sensitivity (dBm)"));
+                ui.add(egui::Slider::new(&mut receiver.gain_dbi, -10.0..=20.0).text("ESM Antenna Gain (dBi)"));
+                if receiver != *esm_receiver {
+                    *esm_receiver = receiver;
+                }
+                for radar in radars.iter() {
+                    let range = crate::physics::esm::intercept_range(radar, &receiver);
+                    ui.label(format!("{}: free-space intercept {:.0} km", radar.name, range / 1000.0));
+                }
+            }
             
-            egui::ComboBox::from_label("Target RCS Profile")
-                .selected_text(controller.rcs_profile.label())
-                .show_ui(ui, |ui| {
-                    ui.selectable_value(&mut controller.rcs_profile, RCSProfile::StealthFighter, RCSProfile::StealthFighter.label());
-                    ui.selectable_value(&mut controller.rcs_profile, RCSProfile::Fighter, RCSProfile::Fighter.label());
-                    ui.selectable_value(&mut controller.rcs_profile, RCSProfile::SmallAircraft, RCSProfile::SmallAircraft.label());
-                    ui.selectable_value(&mut controller.rcs_profile, RCSProfile::LargeAircraft, RCSProfile::LargeAircraft.label());
-                    ui.selectable_value(&mut controller.rcs_profile, RCSProfile::Ship, RCSProfile::Ship.label());
-                });
+            target_model_ui(ui, &targets, &mut controller);
+
+            ui.checkbox(&mut controller.clutter_enabled, "Ground/Sea Clutter");
+            if controller.clutter_enabled {
+                let mut sea_state = clutter.sea_state;
+                ui.add(egui::Slider::new(&mut sea_state, 0..=6).text("Sea State"));
+                if sea_state != clutter.sea_state {
+                    clutter.sea_state = sea_state;
+                }
+            }
+
+            ui.checkbox(&mut controller.jamming_enabled, "Jamming");
+
+            if controller.layer == CoverageLayer::MinAltitude {
+                min_altitude_legend_ui(ui);
+            } else {
+                if controller.active_composite().is_none() && controller.coloring != CoverageColoring::Class {
+                    coloring_legend_ui(ui, controller.coloring);
+                }
+                coverage_legend_ui(ui);
+            }
         }
         
+        ui.horizontal(|ui| {
+            if ui.selectable_label(windows.vertical.open, "Vertical Coverage Diagram").clicked() {
+                windows.vertical.open = !windows.vertical.open;
+            }
+            if ui.selectable_label(windows.horizon.open, "Horizon Profile").clicked() {
+                windows.horizon.open = !windows.horizon.open;
+            }
+        });
+        ui.horizontal(|ui| {
+            if ui.selectable_label(windows.redundancy.open, "Redundancy / N-1").clicked() {
+                windows.redundancy.open = !windows.redundancy.open;
+            }
+            if ui.selectable_label(windows.volume.open, "Coverage Volume").clicked() {
+                windows.volume.open = !windows.volume.open;
+            }
+            if ui.selectable_label(windows.statistics.open, "Statistics").clicked() {
+                windows.statistics.open = !windows.statistics.open;
+            }
+        });
+
         ui.separator();
         ui.heading("Metrics");
 
         ui.label(format!("Tiles Computed: {}", metrics.tiles_computed));
         ui.label(format!("Cache Hits: {}", metrics.cache_hits));
         
+        if controller.jamming_enabled {
+            ui.separator();
+            ui.heading("Electronic Attack");
+            if let Some(target) = targets.targets.get(controller.target_index) {
+                electronic_attack_ui(ui, &radars, &jammers, target);
+            }
+        }
+
         ui.separator();
         ui.heading("Radars");
         ui.label(format!("Loaded: {}", radars.iter().count()));
         
-            for radar in radars.iter() {
-                ui.collapsing(&radar.name, |ui| {
+            for mut radar in radars.iter_mut() {
+                let name = radar.name.clone();
+                ui.collapsing(&name, |ui| {
                     ui.label(format!("Freq: {:.1} MHz", radar.frequency_mhz));
                     ui.label(format!("Power: {:.1} W", radar.tx_power_w));
+                    ui.label(format!("Platform: {} (antenna {:.0} m AMSL)", radar.platform.label(), radar.antenna_altitude_amsl()));
+                    if let Some(min) = radar.min_range_m {
+                        ui.label(format!("Min Range: {:.1} km", min / 1000.0));
+                    }
+                    if let Some(max) = radar.instrumented_range_m {
+                        ui.label(format!("Instrumented Range: {:.0} km", max / 1000.0));
+                    }
+                    if let Some(max) = radar.max_elevation_deg {
+                        ui.label(format!("Max Elevation: {:.1}°", max));
+                    }
+                    if radar.mti_improvement_db > 0.0 {
+                        ui.label(format!("MTI Improvement: {:.0} dB", radar.mti_improvement_db));
+                    }
+                    if let Some(waveform) = radar.waveform {
+                        ui.label(format!("PRF: {:.0} Hz, Pulse: {:.1} µs, Duty: {:.2}%, Average Power: {:.0} W",
+                            waveform.prf_hz, waveform.pulse_width_us, waveform.duty_cycle() * 100.0, radar.average_power_w()));
+                        ui.label(format!("Unambiguous Range: {:.0} km", waveform.unambiguous_range_m() / 1000.0));
+                        // Range holes from transmit blanking, up to the displayed range
+                        let max_range = radar.instrumented_range_m.unwrap_or(4.0 * waveform.unambiguous_range_m());
+                        let bands = waveform.blind_range_bands(max_range);
+                        let mut text: Vec<String> = bands.iter().take(6)
+                            .map(|(start, end)| format!("{:.1}-{:.1}", start / 1000.0, end / 1000.0))
+                            .collect();
+                        if bands.len() > 6 {
+                            text.push(format!("... ({} bands)", bands.len()));
+                        }
+                        ui.label(format!("Blind Ranges (km): {}", text.join(", ")));
+                    }
+
+                    // Work on a copy so the radar is only marked changed on actual edits
+                    let mut detection = radar.detection;
+                    let mut use_model = detection.is_some();
+                    ui.checkbox(&mut use_model, "Pd/Pfa detection model");
+                    if use_model {
+                        let params = detection.get_or_insert_with(DetectionParams::default);
+                        detection_params_ui(ui, &name, params);
+                        ui.label(format!("Required SNR: {:.1} dB", params.required_snr_db()));
+                    } else {
+                        detection = None;
+                        ui.label(format!("SNR threshold: {:.1} dB", radar.snr_threshold_db));
+                    }
+                    if detection != radar.detection {
+                        radar.detection = detection;
+                    }
                 });
             }
 
             ui.separator();
             ui.heading("Processing");
             // Check for active viewshed tasks
-             for (radar, progress) in computing_radars.iter() {
-                let current = progress.current.load(Ordering::Relaxed) as f32;
-                let total = progress.total as f32;
-                let percent = (current / total).clamp(0.0, 1.0);
+             for (entity, progress) in computing_radars.iter() {
+                let Ok(radar) = radars.get(entity) else { continue };
                 ui.label(format!("Computing Viewshed: {}", radar.name));
-                ui.add(egui::ProgressBar::new(percent).show_percentage());
+                ui.add(egui::ProgressBar::new(progress.fraction()).show_percentage());
+            }
+            // Background jobs, visible tiles are served first
+            for (name, stats) in [("Terrain", jobs.terrain), ("Viewshed", jobs.viewshed), ("Coverage", jobs.coverage), ("Volume", jobs.volume)] {
+                ui.label(format!(
+                    "{}: {} queued, {} running, {} done, {} cancelled ({} stopping)",
+                    name, stats.queued, stats.running, stats.completed, stats.cancelled, stats.cancelling
+                ));
+            }
+            for (name, stats) in [("Coverage", jobs.coverage), ("Volume", jobs.volume)] {
+                if stats.running > 0 {
+                    ui.add(egui::ProgressBar::new(stats.progress).text(name));
+                }
             }
 
             ui.separator();
             ui.label(format!("Zoom: {:.5}", controller.zoom));
         });
 }
+
+fn detection_params_ui(ui: &mut egui::Ui, id: &str, params: &mut DetectionParams) {
+    ui.add(egui::Slider::new(&mut params.pd, 0.1..=0.99).text("Required Pd"));
+
+    egui::ComboBox::from_id_salt((id, "pfa"))
+        .selected_text(format!("Pfa {:.0e}", params.pfa))
+        .show_ui(ui, |ui| {
+            for pfa in [1e-3, 1e-4, 1e-5, 1e-6, 1e-7, 1e-8] {
+                ui.selectable_value(&mut params.pfa, pfa, format!("{:.0e}", pfa));
+            }
+        });
+
+    ui.add(egui::Slider::new(&mut params.n_pulses, 1..=100).text("Integrated Pulses"));
+
+    egui::ComboBox::from_id_salt((id, "swerling"))
+        .selected_text(params.swerling.label())
+        .show_ui(ui, |ui| {
+            for model in SwerlingModel::ALL {
+                ui.selectable_value(&mut params.swerling, model, model.label());
+            }
+        });
+}
+
+/// Transmitter and receivers of the bistatic layer, by radar name
+fn bistatic_ui(ui: &mut egui::Ui, controller: &mut MapController, radar_names: &[String]) {
+    let selected = controller.bistatic_transmitter.clone().unwrap_or_else(|| "Select radar".to_string());
+    egui::ComboBox::from_label("Transmitter")
+        .selected_text(selected)
+        .show_ui(ui, |ui| {
+            for name in radar_names {
+                ui.selectable_value(&mut controller.bistatic_transmitter, Some(name.clone()), name);
+            }
+        });
+    ui.label("Receivers:");
+    for name in radar_names {
+        let mut listening = controller.bistatic_receivers.contains(name);
+        if ui.checkbox(&mut listening, name).changed() {
+            if listening {
+                controller.bistatic_receivers.push(name.clone());
+                controller.bistatic_receivers.sort();
+            } else {
+                controller.bistatic_receivers.retain(|r| r != name);
+            }
+        }
+    }
+}
+
+/// Network composite controls; `radar_names` in network order (sorted by name)
+fn composite_ui(ui: &mut egui::Ui, composite: &mut Option<CompositeMode>, radar_names: &[String]) {
+    let mut fused = composite.is_some();
+    ui.checkbox(&mut fused, "Fuse Radars (network composite)");
+    if !fused {
+        *composite = None;
+        return;
+    }
+
+    let mut mode = composite.unwrap_or_default();
+    let at_least = match mode {
+        CompositeMode::AtLeast(n) => CompositeMode::AtLeast(n),
+        _ => CompositeMode::AtLeast(2),
+    };
+    let without = match mode {
+        CompositeMode::WithoutRadar(radar) => CompositeMode::WithoutRadar(radar),
+        _ => CompositeMode::WithoutRadar(0),
+    };
+    egui::ComboBox::from_label("Composite")
+        .selected_text(mode.label())
+        .show_ui(ui, |ui| {
+            ui.selectable_value(&mut mode, CompositeMode::Any, CompositeMode::Any.label());
+            ui.selectable_value(&mut mode, at_least, at_least.label());
+            ui.selectable_value(&mut mode, CompositeMode::BestRadar, CompositeMode::BestRadar.label());
+            ui.selectable_value(&mut mode, CompositeMode::Redundancy, CompositeMode::Redundancy.label());
+            ui.selectable_value(&mut mode, without, without.label());
+        });
+    match &mut mode {
+        CompositeMode::AtLeast(n) => {
+            let max = radar_names.len().clamp(1, u8::MAX as usize) as u8;
+            ui.add(egui::Slider::new(n, 1..=max).text("Radars"));
+            ui.label("Amber: seen by fewer radars");
+        }
+        CompositeMode::BestRadar => {
+            use crate::render::radar_palette_color;
+            for (i, name) in radar_names.iter().enumerate() {
+                let [r, g, b, _] = radar_palette_color(i.min(u8::MAX as usize) as u8);
+                ui.horizontal(|ui| {
+                    ui.colored_label(egui::Color32::from_rgb(r, g, b), "■");
+                    ui.label(name);
+                });
+            }
+        }
+        CompositeMode::Redundancy => {
+            use crate::render::redundancy_color;
+            for (count, label) in [(1, "1 radar (single point of failure)"), (2, "2 radars"), (3, "3+ radars")] {
+                let [r, g, b, _] = redundancy_color(count);
+                ui.horizontal(|ui| {
+                    ui.colored_label(egui::Color32::from_rgb(r, g, b), "■");
+                    ui.label(label);
+                });
+            }
+        }
+        CompositeMode::WithoutRadar(radar) => {
+            let selected = radar_names.get(*radar as usize).map_or("Select radar", String::as_str);
+            egui::ComboBox::from_label("Failed radar")
+                .selected_text(selected)
+                .show_ui(ui, |ui| {
+                    for (i, name) in radar_names.iter().enumerate().take(u8::MAX as usize) {
+                        ui.selectable_value(radar, i as u8, name);
+                    }
+                });
+            ui.label("Red: coverage lost, green: coverage kept");
+        }
+        CompositeMode::Any => {}
+    }
+    if *composite != Some(mode) {
+        *composite = Some(mode);
+    }
+}
+
+fn coloring_legend_ui(ui: &mut egui::Ui, coloring: CoverageColoring) {
+    use crate::render::ramp_color;
+
+    let (low, high) = coloring.range();
+    for t in [0.0, 0.25, 0.5, 0.75, 1.0] {
+        let [r, g, b, _] = ramp_color(t);
+        ui.horizontal(|ui| {
+            ui.colored_label(egui::Color32::from_rgb(r, g, b), "■");
+            ui.label(format!("{:.2}", low + t * (high - low)));
+        });
+    }
+    ui.label("Other cells by class:");
+}
+
+fn coverage_legend_ui(ui: &mut egui::Ui) {
+    use crate::coverage::CoverageClass;
+    use crate::render::coverage_class_color;
+
+    let entries = [
+        (CoverageClass::Visible, "Detected"),
+        (CoverageClass::Shadowed, "Terrain shadow"),
+        (CoverageClass::ConeOfSilence, "Cone of silence"),
+        (CoverageClass::BelowMinRange, "Below min range"),
+        (CoverageClass::BeyondInstrumented, "Beyond instrumented range"),
+        (CoverageClass::BlindRange, "Blind range (eclipsed)"),
+        (CoverageClass::RangeAmbiguous, "Range ambiguous"),
+        (CoverageClass::ClutterMasked, "Clutter limited"),
+        (CoverageClass::Jammed, "Lost to jamming"),
+    ];
+    for (class, label) in entries {
+        let [r, g, b, _] = coverage_class_color(class);
+        ui.horizontal(|ui| {
+            ui.colored_label(egui::Color32::from_rgb(r, g, b), "■");
+            ui.label(label);
+        });
+    }
+    clutter_hatch_legend_ui(ui);
+}
+
+// Detected but clutter-limited cells are hatched over whatever colouring is shown
+fn clutter_hatch_legend_ui(ui: &mut egui::Ui) {
+    let [r, g, b, _] = crate::render::CLUTTER_HATCH_COLOR;
+    ui.horizontal(|ui| {
+        ui.colored_label(egui::Color32::from_rgb(r, g, b), "▨");
+        ui.label("Detected, clutter limited (hatched)");
+    });
+}
+
+/// Altitude mapped to the red end of the minimum altitude colour ramp
+pub const MIN_ALTITUDE_DISPLAY_MAX_M: f32 = 6000.0;
+
+fn min_altitude_legend_ui(ui: &mut egui::Ui) {
+    use crate::render::min_altitude_color;
+
+    for altitude in [0.0, 1500.0, 3000.0, 4500.0, MIN_ALTITUDE_DISPLAY_MAX_M] {
+        let [r, g, b, _] = min_altitude_color(Some(altitude), MIN_ALTITUDE_DISPLAY_MAX_M);
+        ui.horizontal(|ui| {
+            ui.colored_label(egui::Color32::from_rgb(r, g, b), "■");
+            ui.label(format!("{:.0} m", altitude));
+        });
+    }
+    let [r, g, b, _] = min_altitude_color(None, MIN_ALTITUDE_DISPLAY_MAX_M);
+    ui.horizontal(|ui| {
+        ui.colored_label(egui::Color32::from_rgb(r, g, b), "■");
+        ui.label("Not detected below ceiling");
+    });
+}
+
+// Target altitude and its reference. Flight levels are edited in FL but stored as
+// pressure altitude in metres, like the other references.
+fn target_altitude_ui(ui: &mut egui::Ui, controller: &mut MapController) {
+    use crate::coverage::{flight_level_to_m, m_to_flight_level};
+
+    let previous = controller.altitude_reference;
+    ui.horizontal(|ui| {
+        for reference in [AltitudeReference::Agl, AltitudeReference::Amsl, AltitudeReference::FlightLevel] {
+            ui.radio_value(&mut controller.altitude_reference, reference, reference.label());
+        }
+    });
+    if controller.altitude_reference != previous {
+        // Keep the value in the new slider's range
+        let (lo, hi) = altitude_range(controller.altitude_reference);
+        controller.target_altitude = controller.target_altitude.clamp(lo, hi);
+    }
+
+    let (lo, hi) = altitude_range(controller.altitude_reference);
+    ui.horizontal(|ui| match controller.altitude_reference {
+        AltitudeReference::FlightLevel => {
+            let mut level = m_to_flight_level(controller.target_altitude as f64).round() as i32;
+            if ui.button("<").clicked() {
+                level -= 10;
+            }
+            ui.add(egui::Slider::new(&mut level, 0..=450).text("Target FL"));
+            if ui.button(">").clicked() {
+                level += 10;
+            }
+            controller.target_altitude = flight_level_to_m(level.clamp(0, 450) as f64) as f32;
+        }
+        reference => {
+            if ui.button("<").clicked() {
+                controller.target_altitude = (controller.target_altitude - 50.0).max(lo);
+            }
+            ui.add(egui::Slider::new(&mut controller.target_altitude, lo..=hi).text(format!("Target {} (m)", reference.label())));
+            if ui.button(">").clicked() {
+                controller.target_altitude = (controller.target_altitude + 50.0).min(hi);
+            }
+        }
+    });
+    if controller.altitude_reference == AltitudeReference::FlightLevel {
+        ui.add(egui::Slider::new(&mut controller.qnh_hpa, 950.0..=1050.0).text("QNH (hPa)"));
+    }
+}
+
+// Slider range of the target altitude (m) per reference
+fn altitude_range(reference: AltitudeReference) -> (f32, f32) {
+    match reference {
+        AltitudeReference::Agl => (10.0, 2000.0),
+        AltitudeReference::Amsl => (0.0, 10_000.0),
+        AltitudeReference::FlightLevel => (0.0, crate::coverage::flight_level_to_m(450.0) as f32),
+    }
+}
+
+fn target_model_ui(ui: &mut egui::Ui, library: &TargetLibrary, controller: &mut MapController) {
+    let selected = library.targets.get(controller.target_index).map_or("None", |t| t.name.as_str());
+    egui::ComboBox::from_label("Target Model")
+        .selected_text(selected)
+        .show_ui(ui, |ui| {
+            for (i, target) in library.targets.iter().enumerate() {
+                ui.selectable_value(&mut controller.target_index, i, &target.name);
+            }
+        });
+
+    let mut aspect = controller.aspect;
+    egui::ComboBox::from_label("Target Aspect")
+        .selected_text(aspect.label())
+        .show_ui(ui, |ui| {
+            let heading = match aspect {
+                AspectMode::Heading(h) => h,
+                _ => 0.0,
+            };
+            ui.selectable_value(&mut aspect, AspectMode::Heading(heading), AspectMode::Heading(heading).label());
+            ui.selectable_value(&mut aspect, AspectMode::WorstCase, AspectMode::WorstCase.label());
+            ui.selectable_value(&mut aspect, AspectMode::Average, AspectMode::Average.label());
+        });
+    if let AspectMode::Heading(heading) = &mut aspect {
+        ui.add(egui::Slider::new(heading, 0.0..=359.0).text("Target Heading (°)"));
+    }
+    if aspect != controller.aspect {
+        controller.aspect = aspect;
+    }
+
+    if let Some(target) = library.targets.get(controller.target_index) {
+        let bands: Vec<String> = target.bands.iter().map(|b| format!("{:?}", b.band)).collect();
+        ui.label(format!("RCS tables: {} band", bands.join(", ")));
+    }
+}
+
+fn electronic_attack_ui(
+    ui: &mut egui::Ui,
+    radars: &Query<&mut crate::io::Radar>,
+    jammers: &Query<&crate::io::Jammer>,
+    target: &TargetModel,
+) {
+    use crate::io::JammerGeometry;
+    use crate::physics::radar_eq::{calculate_jamming_power_w, self_protection_burn_through_range, stand_off_burn_through_range};
+    use crate::physics::los::calculate_geodesic;
+
+    for jammer in jammers.iter() {
+        ui.collapsing(&jammer.name, |ui| {
+            ui.label(format!("ERP: {:.0} W, Bandwidth: {:.0} MHz", jammer.erp_w, jammer.bandwidth_hz / 1e6));
+            for radar in radars.iter() {
+                // Inbound target seen nose-on; jammer in the main beam (worst case for stand-off)
+                let rcs = target.nose_rcs_sqm(radar.frequency_mhz);
+                let burn_through = match jammer.geometry {
+                    JammerGeometry::SelfProtection => self_protection_burn_through_range(radar, jammer, rcs),
+                    JammerGeometry::StandOff => {
+                        let (dist, _) = calculate_geodesic(radar.location, jammer.location);
+                        let j = calculate_jamming_power_w(radar, jammer, jammer.erp_w, dist, radar.gain_dbi);
+                        stand_off_burn_through_range(radar, rcs, j)
+                    }
+                };
+                ui.label(format!("{}: burn-through {:.1} km", radar.name, burn_through / 1000.0));
+            }
+        });
+    }
+}
+
+/// State of the vertical coverage (range–height) window.
+/// The UI sets `compute_requested`; the diagram is computed where the terrain lives.
+#[derive(Resource, Default)]
+pub struct VerticalCoverageView {
+    pub open: bool,
+    pub radar_name: Option<String>,
+    pub params: crate::coverage::vertical::VerticalCoverageParams,
+    pub compute_requested: bool,
+    pub diagram: Option<crate::coverage::vertical::VerticalCoverage>,
+    texture: Option<egui::TextureHandle>,
+}
+
+impl VerticalCoverageView {
+    /// Store a new diagram; its texture is rebuilt on the next UI frame
+    pub fn set_diagram(&mut self, diagram: crate::coverage::vertical::VerticalCoverage) {
+        self.diagram = Some(diagram);
+        self.texture = None;
+    }
+}
+
+pub fn vertical_coverage_ui_system(
+    mut contexts: EguiContexts,
+    mut view: ResMut<VerticalCoverageView>,
+    radars: Query<&crate::io::Radar>,
+) {
+    let ctx = match contexts.try_ctx_mut() {
+        Some(ctx) => ctx.clone(),
+        None => return,
+    };
+    let view = &mut *view;
+
+    let mut open = view.open;
+    egui::Window::new("Vertical Coverage").open(&mut open).show(&ctx, |ui| {
+        let selected = view.radar_name.clone().unwrap_or_else(|| "Select radar".to_string());
+        egui::ComboBox::from_label("Radar")
+            .selected_text(selected)
+            .show_ui(ui, |ui| {
+                for radar in radars.iter() {
+                    ui.selectable_value(&mut view.radar_name, Some(radar.name.clone()), &radar.name);
+                }
+            });
+        ui.add(egui::Slider::new(&mut view.params.azimuth_deg, 0.0..=359.0).text("Azimuth (°)"));
+        ui.add(egui::Slider::new(&mut view.params.max_range_m, 10_000.0..=500_000.0).text("Max Range (m)"));
+        ui.add(egui::Slider::new(&mut view.params.max_height_m, 1_000.0..=30_000.0).text("Max Height AMSL (m)"));
+
+        ui.horizontal(|ui| {
+            if ui.add_enabled(view.radar_name.is_some(), egui::Button::new("Compute")).clicked() {
+                view.compute_requested = true;
+            }
+            if let Some(diagram) = &view.diagram
+                && ui.button("Export CSV").clicked()
+            {
+                let path = format!("vertical_{}_{:03.0}.csv", diagram.radar_name.replace(' ', "_"), diagram.azimuth_deg);
+                report_export(diagram.save_csv(&path), &path);
+            }
+        });
+
+        let Some(diagram) = &view.diagram else { return };
+        let texture = view.texture.get_or_insert_with(|| {
+            let pixels = crate::render::vertical_coverage_pixels(diagram);
+            let image = egui::ColorImage::from_rgba_unmultiplied([diagram.width(), diagram.height()], &pixels);
+            ui.ctx().load_texture("vertical_coverage", image, egui::TextureOptions::NEAREST)
+        });
+
+        ui.label(format!("{} — azimuth {:.0}°", diagram.radar_name, diagram.azimuth_deg));
+        ui.horizontal(|ui| {
+            ui.label(format!("{:.0} m", diagram.heights_m.last().copied().unwrap_or(0.0)));
+            ui.image((texture.id(), egui::vec2(600.0, 300.0)));
+        });
+        ui.label(format!("0 — {:.0} km", diagram.ranges_m.last().copied().unwrap_or(0.0) / 1000.0));
+    });
+    view.open = open;
+}
+
+/// Redundancy and N-1 report of the fused network coverage. The app sums the
+/// per-tile summaries of the composite layer into `summary`.
+#[derive(Resource, Default)]
+pub struct RedundancyView {
+    pub open: bool,
+    pub radar_names: Vec<String>, // Network order
+    pub tiles: usize,
+    pub summary: crate::coverage::redundancy::RedundancySummary,
+}
+
+pub fn redundancy_ui_system(
+    mut contexts: EguiContexts,
+    mut view: ResMut<RedundancyView>,
+) {
+    let ctx = match contexts.try_ctx_mut() {
+        Some(ctx) => ctx.clone(),
+        None => return,
+    };
+    let view = &mut *view;
+
+    let mut open = view.open;
+    egui::Window::new("Redundancy / N-1").open(&mut open).show(&ctx, |ui| {
+        if view.tiles == 0 {
+            ui.label("Enable \"Fuse Radars\" on the detection layer to build the report.");
+            return;
+        }
+        let summary = &view.summary;
+        let covered = summary.covered_km2();
+        let percent = |area: f64| if covered > 0.0 { area / covered * 100.0 } else { 0.0 };
+        ui.label(format!("{} tiles, {:.0} km² covered", view.tiles, covered));
+
+        egui::Grid::new("redundancy_table").striped(true).show(ui, |ui| {
+            ui.strong("Radars");
+            ui.strong("Area (km²)");
+            ui.strong("Share");
+            ui.end_row();
+            for (label, area) in [("1", summary.single_km2), ("2", summary.double_km2), ("3+", summary.triple_plus_km2)] {
+                ui.label(label);
+                ui.label(format!("{:.0}", area));
+                ui.label(format!("{:.1} %", percent(area)));
+                ui.end_row();
+            }
+        });
+
+        ui.separator();
+        ui.label("N-1: coverage lost when one radar fails");
+        egui::Grid::new("n_minus_one_table").striped(true).show(ui, |ui| {
+            ui.strong("Failed radar");
+            ui.strong("Lost (km²)");
+            ui.strong("Share");
+            ui.end_row();
+            for (radar, area) in summary.sole_km2.iter().enumerate() {
+                ui.label(view.radar_names.get(radar).map_or("?", String::as_str));
+                ui.label(format!("{:.0}", area));
+                ui.label(format!("{:.1} %", summary.loss_percent(radar)));
+                ui.end_row();
+            }
+        });
+
+        if ui.button("Export CSV").clicked() {
+            let path = "redundancy.csv";
+            report_export(summary.save_csv(path, &view.radar_names), path);
+        }
+    });
+    view.open = open;
+}
+
+/// Coverage statistics window. The app computes the statistics of each coverage
+/// tile once (1° sectors) and sums them per radar and for the network into `report`,
+/// with the sector ranges regrouped into `sectors`.
+#[derive(Resource)]
+pub struct CoverageStatisticsView {
+    pub open: bool,
+    pub sectors: usize,
+    pub report: crate::coverage::statistics::StatisticsReport,
+}
+
+impl Default for CoverageStatisticsView {
+    fn default() -> Self {
+        Self { open: false, sectors: 12, report: Default::default() }
+    }
+}
+
+pub fn coverage_statistics_ui_system(
+    mut contexts: EguiContexts,
+    mut view: ResMut<CoverageStatisticsView>,
+    controller: Res<MapController>,
+) {
+    let ctx = match contexts.try_ctx_mut() {
+        Some(ctx) => ctx.clone(),
+        None => return,
+    };
+    let view = &mut *view;
+
+    let mut open = view.open;
+    egui::Window::new("Coverage Statistics").open(&mut open).show(&ctx, |ui| {
+        let report = &view.report;
+        if !controller.layer.detects_target() {
+            ui.label(format!("No detection statistics for the {} layer.", controller.layer.label()));
+            return;
+        }
+        if report.radars.is_empty() {
+            ui.label("Enable \"Show Coverage\" to compute the statistics.");
+            return;
+        }
+        ui.label(format!("Region of interest: {} tiles, {:.0} km² (WGS84)", report.network.tiles, report.network.region_km2));
+
+        egui::Grid::new("statistics_table").striped(true).show(ui, |ui| {
+            for title in ["", "Covered (km²)", "Covered", "Shadowed (km²)", "Shadowed", "Max range (km)"] {
+                ui.strong(title);
+            }
+            ui.end_row();
+            let rows = report.radars.iter().map(|r| (r.name.as_str(), &r.statistics)).chain([("Network", &report.network)]);
+            for (name, stats) in rows {
+                ui.label(name);
+                ui.label(format!("{:.0}", stats.covered_km2));
+                ui.label(format!("{:.1} %", stats.covered_percent()));
+                ui.label(format!("{:.0}", stats.shadowed_km2));
+                ui.label(format!("{:.1} %", stats.shadowed_percent()));
+                ui.label(if stats.sector_range_m.is_empty() { "-".to_string() } else { format!("{:.0}", stats.max_range_m() / 1000.0) });
+                ui.end_row();
+            }
+        });
+
+        ui.separator();
+        ui.horizontal(|ui| {
+            ui.label("Detection range per sector (km)");
+            egui::ComboBox::from_id_salt("statistics_sectors")
+                .selected_text(format!("{}°", 360 / view.sectors))
+                .show_ui(ui, |ui| {
+                    for sectors in [4, 8, 12, 36] {
+                        ui.selectable_value(&mut view.sectors, sectors, format!("{}°", 360 / sectors));
+                    }
+                });
+        });
+        let report = &view.report;
+        egui::ScrollArea::vertical().max_height(250.0).show(ui, |ui| {
+            egui::Grid::new("sector_range_table").striped(true).show(ui, |ui| {
+                ui.strong("Sector");
+                for radar in &report.radars {
+                    ui.strong(&radar.name);
+                }
+                ui.end_row();
+                let width = 360 / view.sectors.max(1);
+                for sector in 0..view.sectors {
+                    ui.label(format!("{:03}-{:03}°", sector * width, (sector + 1) * width));
+                    for radar in &report.radars {
+                        let range = radar.statistics.sector_range_m.get(sector).copied().unwrap_or(0.0);
+                        ui.label(format!("{:.0}", range / 1000.0));
+                    }
+                    ui.end_row();
+                }
+            });
+        });
+
+        ui.horizontal(|ui| {
+            if ui.button("Export CSV").clicked() {
+                report_export(report.save_csv("coverage_statistics.csv"), "coverage_statistics.csv");
+            }
+            if ui.button("Export JSON").clicked() {
+                report_export(report.save_json("coverage_statistics.json"), "coverage_statistics.json");
+            }
+        });
+    });
+    view.open = open;
+}
+
+/// State of the coverage volume window. The app computes the volume of the selected
+/// radar over the coverage area, tile by tile; the scene shows the selected slice
+/// (or every layer stacked) at the layers' altitudes.
+#[derive(Resource)]
+pub struct CoverageVolumeView {
+    pub open: bool,
+    pub radar_name: Option<String>,
+    pub reference: crate::coverage::AltitudeReference,
+    pub lowest_m: f64,
+    pub highest_m: f64,
+    pub layers: usize,
+    pub compute_requested: bool,
+    pub volumes: Vec<crate::coverage::volume::CoverageVolume>,
+    pub pending: usize, // Tiles still queued or computing
+    pub slice: usize,
+    pub show_all: bool,
+    pub query: LatLon, // Point to sample, altitude in `reference`
+}
+
+impl Default for CoverageVolumeView {
+    fn default() -> Self {
+        let params = crate::coverage::volume::CoverageVolumeParams::default();
+        Self {
+            open: false,
+            radar_name: None,
+            reference: params.reference,
+            lowest_m: params.altitudes_m.first().copied().unwrap_or(0.0),
+            highest_m: params.altitudes_m.last().copied().unwrap_or(0.0),
+            layers: params.altitudes_m.len(),
+            compute_requested: false,
+            volumes: Vec::new(),
+            pending: 0,
+            slice: 0,
+            show_all: false,
+            query: LatLon { latitude: 45.5, longitude: 5.5, altitude: 1000.0 },
+        }
+    }
+}
+
+impl CoverageVolumeView {
+    pub fn params(&self) -> crate::coverage::volume::CoverageVolumeParams {
+        crate::coverage::volume::CoverageVolumeParams::evenly_spaced(self.reference, self.lowest_m, self.highest_m, self.layers)
+    }
+
+    /// Layer altitudes of the computed volume
+    pub fn altitudes_m(&self) -> &[f64] {
+        self.volumes.first().map_or(&[], |v| v.altitudes_m.as_slice())
+    }
+
+    pub fn sample(&self, point: LatLon) -> Option<crate::coverage::volume::VolumeSample> {
+        self.volumes.iter().find_map(|v| v.sample(point.latitude, point.longitude, point.altitude))
+    }
+}
+
+pub fn coverage_volume_ui_system(
+    mut contexts: EguiContexts,
+    mut view: ResMut<CoverageVolumeView>,
+    radars: Query<&crate::io::Radar>,
+) {
+    let ctx = match contexts.try_ctx_mut() {
+        Some(ctx) => ctx.clone(),
+        None => return,
+    };
+    let view = &mut *view;
+
+    let mut open = view.open;
+    egui::Window::new("Coverage Volume").open(&mut open).show(&ctx, |ui| {
+        let selected = view.radar_name.clone().unwrap_or_else(|| "Select radar".to_string());
+        egui::ComboBox::from_label("Radar")
+            .selected_text(selected)
+            .show_ui(ui, |ui| {
+                for radar in radars.iter() {
+                    ui.selectable_value(&mut view.radar_name, Some(radar.name.clone()), &radar.name);
+                }
+            });
+        ui.horizontal(|ui| {
+            ui.radio_value(&mut view.reference, AltitudeReference::Agl, "AGL");
+            ui.radio_value(&mut view.reference, AltitudeReference::Amsl, "AMSL");
+            // Pressure altitude in metres; FL = m / 30.48
+            ui.radio_value(&mut view.reference, AltitudeReference::FlightLevel, "Pressure alt.");
+        });
+        ui.add(egui::Slider::new(&mut view.lowest_m, 0.0..=20_000.0).text("Lowest Layer (m)"));
+        ui.add(egui::Slider::new(&mut view.highest_m, 0.0..=20_000.0).text("Highest Layer (m)"));
+        ui.add(egui::Slider::new(&mut view.layers, 1..=30).text("Layers"));
+
+        ui.horizontal(|ui| {
+            if ui.add_enabled(view.radar_name.is_some(), egui::Button::new("Compute")).clicked() {
+                view.compute_requested = true;
+            }
+            if view.pending > 0 {
+                ui.spinner();
+                ui.label(format!("{} tiles done, {} pending", view.volumes.len(), view.pending));
+            }
+        });
+
+        let altitudes = view.altitudes_m().to_vec();
+        if altitudes.is_empty() {
+            return;
+        }
+        ui.separator();
+        view.slice = view.slice.min(altitudes.len() - 1);
+        let label = format!("Slice: {:.0} m {}", altitudes[view.slice], view.volumes[0].reference.label());
+        ui.add(egui::Slider::new(&mut view.slice, 0..=altitudes.len() - 1).text(label));
+        ui.checkbox(&mut view.show_all, "Show All Layers");
+
+        ui.separator();
+        ui.label("Query");
+        ui.horizontal(|ui| {
+            ui.add(egui::DragValue::new(&mut view.query.latitude).speed(0.01).prefix("Lat: "));
+            ui.add(egui::DragValue::new(&mut view.query.longitude).speed(0.01).prefix("Lon: "));
+            ui.add(egui::DragValue::new(&mut view.query.altitude).speed(10.0).prefix("Alt: ").suffix(" m"));
+        });
+        match view.sample(view.query) {
+            Some(sample) => {
+                ui.label(format!("{:?}", sample.class));
+                // Where a second-time-around echo shows up on the display
+                let radar = radars.iter().find(|r| Some(&r.name) == view.radar_name.as_ref());
+                if let (crate::coverage::CoverageClass::RangeAmbiguous, Some(radar)) = (sample.class, radar)
+                    && let Some(waveform) = radar.waveform
+                {
+                    let (range, _) = crate::physics::los::calculate_geodesic(radar.location, view.query);
+                    ui.label(format!("True range {:.0} km, appears at {:.0} km", range / 1000.0, waveform.apparent_range_m(range) / 1000.0));
+                }
+                ui.label(format!("Clearance: {:.2}°, SNR margin: {:.1} dB", sample.clearance_deg, sample.snr_margin_db));
+                if let Some(pd) = sample.pd {
+                    ui.label(format!("Pd: {:.2}", pd));
+                }
+            }
+            None => {
+                ui.label("Outside the computed volume");
+            }
+        }
+    });
+    view.open = open;
+}
+
+/// State of the horizon profile window; computed in the app like the vertical diagram
+#[derive(Resource)]
+pub struct HorizonProfileView {
+    pub open: bool,
+    pub radar_name: Option<String>,
+    pub azimuth_step_deg: f64,
+    pub max_range_m: f64,
+    pub compute_requested: bool,
+    pub computing: bool, // A profile job is queued or running
+    pub profile: Option<crate::physics::horizon::HorizonProfile>,
+}
+
+impl Default for HorizonProfileView {
+    fn default() -> Self {
+        Self {
+            open: false,
+            radar_name: None,
+            azimuth_step_deg: 1.0,
+            max_range_m: 200_000.0,
+            compute_requested: false,
+            computing: false,
+            profile: None,
+        }
+    }
+}
+
+pub fn horizon_profile_ui_system(
+    mut contexts: EguiContexts,
+    mut view: ResMut<HorizonProfileView>,
+    radars: Query<&crate::io::Radar>,
+) {
+    let ctx = match contexts.try_ctx_mut() {
+        Some(ctx) => ctx.clone(),
+        None => return,
+    };
+    let view = &mut *view;
+
+    let mut open = view.open;
+    egui::Window::new("Horizon Profile").open(&mut open).show(&ctx, |ui| {
+        let selected = view.radar_name.clone().unwrap_or_else(|| "Select radar".to_string());
+        egui::ComboBox::from_label("Radar")
+            .selected_text(selected)
+            .show_ui(ui, |ui| {
+                for radar in radars.iter() {
+                    ui.selectable_value(&mut view.radar_name, Some(radar.name.clone()), &radar.name);
+                }
+            });
+        ui.add(egui::Slider::new(&mut view.azimuth_step_deg, 0.1..=10.0).text("Azimuth Step (°)"));
+        ui.add(egui::Slider::new(&mut view.max_range_m, 10_000.0..=500_000.0).text("Max Range (m)"));
+
+        ui.horizontal(|ui| {
+            if ui.add_enabled(view.radar_name.is_some(), egui::Button::new("Compute")).clicked() {
+                view.compute_requested = true;
+            }
+            if view.computing {
+                ui.spinner();
+            }
+            if let Some(profile) = &view.profile {
+                let stem = format!("horizon_{}", profile.radar_name.replace(' ', "_"));
+                if ui.button("Export CSV").clicked() {
+                    report_export(profile.save_csv(format!("{}.csv", stem)), &stem);
+                }
+                if ui.button("Export JSON").clicked() {
+                    report_export(profile.save_json(format!("{}.json", stem)), &stem);
+                }
+            }
+        });
+
+        if let Some(profile) = &view.profile {
+            horizon_polar_plot(ui, profile);
+        }
+    });
+    view.open = open;
+}
+
+fn report_export(result: anyhow::Result<()>, name: &str) {
+    match result {
+        Ok(()) => println!("Exported {}", name),
+        Err(e) => eprintln!("Export of {} failed: {}", name, e),
+    }
+}
+
+// Polar plot, North up and azimuth clockwise. The radius grows with the masking
+// elevation so high horizons stand out: centre = lowest angle, rim = highest.
+fn horizon_polar_plot(ui: &mut egui::Ui, profile: &crate::physics::horizon::HorizonProfile) {
+    let (min_el, max_el) = profile.samples.iter()
+        .fold((f64::INFINITY, f64::NEG_INFINITY), |(lo, hi), s| (lo.min(s.elevation_deg), hi.max(s.elevation_deg)));
+    if !min_el.is_finite() {
+        return;
+    }
+    // Round the scale to whole degrees
+    let (min_el, max_el) = (min_el.floor(), max_el.ceil().max(min_el.floor() + 1.0));
+
+    let size = 320.0;
+    let (response, painter) = ui.allocate_painter(egui::vec2(size, size), egui::Sense::hover());
+    let center = response.rect.center();
+    let radius = size / 2.0 - 20.0;
+    let to_screen = |azimuth_deg: f64, elevation_deg: f64| {
+        let r = radius * ((elevation_deg - min_el) / (max_el - min_el)) as f32;
+        let a = azimuth_deg.to_radians() as f32;
+        center + egui::vec2(r * a.sin(), -r * a.cos())
+    };
+
+    let grid = egui::Stroke::new(1.0, egui::Color32::DARK_GRAY);
+    let steps = (max_el - min_el).round() as usize;
+    for i in 1..=steps {
+        painter.circle_stroke(center, radius * i as f32 / steps as f32, grid);
+    }
+    for (azimuth, label) in [(0.0, "N"), (90.0, "E"), (180.0, "S"), (270.0, "W")] {
+        painter.line_segment([center, to_screen(azimuth, max_el)], grid);
+        painter.text(to_screen(azimuth, max_el + (max_el - min_el) * 0.08), egui::Align2::CENTER_CENTER, label,
+            egui::FontId::proportional(12.0), egui::Color32::LIGHT_GRAY);
+    }
+
+    let mut points: Vec<egui::Pos2> = profile.samples.iter()
+        .map(|s| to_screen(s.azimuth_deg, s.elevation_deg))
+        .collect();
+    if let Some(first) = points.first().copied() {
+        points.push(first);
+    }
+    painter.add(egui::Shape::line(points, egui::Stroke::new(1.5, egui::Color32::from_rgb(0, 200, 0))));
+
+    ui.label(format!("Centre {:.0}°, rim {:.0}°, {} samples", min_el, max_el, profile.samples.len()));
+}